@@ -4,9 +4,9 @@
 use promptparse::{
     generate::{any_id, bill_payment, slip_verify, true_money},
     generate::{AnyIdConfig, BillPaymentConfig, ProxyType, SlipVerifyConfig, TrueMoneyConfig},
-    parse, parse_barcode,
+    parse, parse_barcode, parse_ref,
     tlv::{checksum, decode, encode, tag, with_crc_tag},
-    validate,
+    validate, Amount,
 };
 
 macro_rules! bench {
@@ -37,11 +37,19 @@ bench!(bench_parse_qr, {
     let _result = parse(payload, true, true);
 });
 
+bench!(bench_parse_qr_ref, {
+    let payload =
+        "00020101021229370016A0000006770101110113006680111111153037645802TH540520.15630442BE";
+    let _result = parse_ref(payload, true, true);
+});
+
 bench!(bench_generate_any_id, {
     let config = AnyIdConfig {
         proxy_type: ProxyType::Msisdn,
         target: "0812223333".to_string(),
-        amount: Some(100.0),
+        amount: Some(Amount::try_from(100.0).unwrap()),
+        tip: None,
+        additional_data: None,
     };
     let _result = any_id(config);
 });
@@ -49,10 +57,12 @@ bench!(bench_generate_any_id, {
 bench!(bench_generate_bill_payment, {
     let config = BillPaymentConfig {
         biller_id: "0112233445566".to_string(),
-        amount: Some(500.0),
+        amount: Some(Amount::try_from(500.0).unwrap()),
+        tip: None,
         ref1: "CUSTOMER001".to_string(),
         ref2: Some("INV001".to_string()),
         ref3: None,
+        additional_data: None,
     };
     let _result = bill_payment(config);
 });
@@ -60,10 +70,11 @@ bench!(bench_generate_bill_payment, {
 bench!(bench_generate_true_money, {
     let config = TrueMoneyConfig {
         mobile_no: "0801111111".to_string(),
-        amount: Some(25.0),
+        amount: Some(Amount::try_from(25.0).unwrap()),
         message: Some("Hello World!".to_string()),
+        additional_data: None,
     };
-    let _result = true_money(config);
+    let _result = true_money(config).unwrap();
 });
 
 bench!(bench_tlv_encode_decode, {
@@ -112,7 +123,9 @@ bench!(bench_full_cycle, {
     let config = AnyIdConfig {
         proxy_type: ProxyType::Msisdn,
         target: "0812223333".to_string(),
-        amount: Some(100.0),
+        amount: Some(Amount::try_from(100.0).unwrap()),
+        tip: None,
+        additional_data: None,
     };
     let qr_code = any_id(config).unwrap();
 
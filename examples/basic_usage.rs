@@ -4,7 +4,7 @@ use promptparse::{
         AnyIdConfig, BillPaymentConfig, BotBarcodeConfig, ProxyType, SlipVerifyConfig,
         TrueMoneyConfig,
     },
-    parse, parse_barcode, validate,
+    parse, parse_barcode, validate, Amount,
 };
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -27,7 +27,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let any_id_config = AnyIdConfig {
         proxy_type: ProxyType::Msisdn,
         target: "0812223333".to_string(),
-        amount: Some(100.50),
+        amount: Some(Amount::try_from(100.50)?),
+        tip: None,
+        additional_data: None,
     };
     let any_id_qr = any_id(any_id_config)?;
     println!("   Generated QR: {any_id_qr}");
@@ -36,10 +38,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n3. Generating Bill Payment QR:");
     let bill_config = BillPaymentConfig {
         biller_id: "1234567890123".to_string(),
-        amount: Some(500.0),
+        amount: Some(Amount::try_from(500.0)?),
+        tip: None,
         ref1: "CUSTOMER001".to_string(),
         ref2: Some("INV001".to_string()),
         ref3: None,
+        additional_data: None,
     };
     let bill_qr = bill_payment(bill_config)?;
     println!("   Generated QR: {bill_qr}");
@@ -48,10 +52,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n4. Generating TrueMoney QR:");
     let true_money_config = TrueMoneyConfig {
         mobile_no: "0801111111".to_string(),
-        amount: Some(25.0),
+        amount: Some(Amount::try_from(25.0)?),
         message: Some("Coffee money".to_string()),
+        additional_data: None,
     };
-    let true_money_qr = true_money(true_money_config);
+    let true_money_qr = true_money(true_money_config)?;
     println!("   Generated QR: {true_money_qr}");
 
     // Example 5: Generate Slip Verify QR
@@ -69,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         biller_id: "099999999999990".to_string(),
         ref1: "111222333444".to_string(),
         ref2: Some("REF2".to_string()),
-        amount: Some(150.0),
+        amount: Some(Amount::try_from(150.0)?),
     };
     let bot_code = bot_barcode(bot_config);
     println!("   Generated Barcode: {bot_code}");
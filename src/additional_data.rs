@@ -0,0 +1,237 @@
+use crate::error::PromptParseError;
+use crate::spec;
+use crate::tlv::{decode, encode, tag, TlvTag};
+use crate::Result;
+
+/// Additional Data Field Template (Tag 62)
+///
+/// Sub-tags 01-08 may be set to `***` to ask the payer's app to prompt
+/// the payer for the value.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AdditionalData {
+    /// Bill Number (62.01)
+    pub bill_number: Option<String>,
+    /// Mobile Number (62.02)
+    pub mobile_number: Option<String>,
+    /// Store Label (62.03)
+    pub store_label: Option<String>,
+    /// Loyalty Number (62.04)
+    pub loyalty_number: Option<String>,
+    /// Reference Label (62.05)
+    pub reference_label: Option<String>,
+    /// Customer Label (62.06)
+    pub customer_label: Option<String>,
+    /// Terminal Label (62.07), used as Reference 3 by PromptPay Bill Payment
+    pub terminal_label: Option<String>,
+    /// Purpose of Transaction (62.08)
+    pub purpose_of_transaction: Option<String>,
+    /// Additional Consumer Data Request (62.09), any of `A` (address),
+    /// `M` (mobile number) and `E` (email)
+    pub additional_consumer_data_request: Option<String>,
+    /// Merchant Tax ID (62.10)
+    pub merchant_tax_id: Option<String>,
+    /// Merchant Channel (62.11)
+    pub merchant_channel: Option<String>,
+    /// RFU (62.12-49) and Payment System specific (62.50-99) data objects
+    pub other: Vec<TlvTag>,
+}
+
+/// Sub-tag ID, name and maximum length of known fields
+const FIELDS: [(&str, &str, usize); 11] = [
+    ("01", "Bill Number", 25),
+    ("02", "Mobile Number", 25),
+    ("03", "Store Label", 25),
+    ("04", "Loyalty Number", 25),
+    ("05", "Reference Label", 25),
+    ("06", "Customer Label", 25),
+    ("07", "Terminal Label", 25),
+    ("08", "Purpose of Transaction", 25),
+    ("09", "Additional Consumer Data Request", 3),
+    ("10", "Merchant Tax ID", 20),
+    ("11", "Merchant Channel", 3),
+];
+
+impl AdditionalData {
+    /// Read fields from the sub-tags of Tag 62
+    pub fn from_tags(tags: &[TlvTag]) -> Self {
+        let mut data = Self::default();
+
+        for tag in tags {
+            let value = Some(tag.value.clone());
+            match tag.id.as_str() {
+                "01" => data.bill_number = value,
+                "02" => data.mobile_number = value,
+                "03" => data.store_label = value,
+                "04" => data.loyalty_number = value,
+                "05" => data.reference_label = value,
+                "06" => data.customer_label = value,
+                "07" => data.terminal_label = value,
+                "08" => data.purpose_of_transaction = value,
+                "09" => data.additional_consumer_data_request = value,
+                "10" => data.merchant_tax_id = value,
+                "11" => data.merchant_channel = value,
+                _ => data.other.push(tag.clone()),
+            }
+        }
+
+        data
+    }
+
+    /// Read fields from Tag 62, decoding its value if it was parsed without sub-tags
+    pub fn from_tag(tag: &TlvTag) -> Result<Self> {
+        match &tag.sub_tags {
+            Some(sub_tags) => Ok(Self::from_tags(sub_tags)),
+            None => Ok(Self::from_tags(&decode(&tag.value)?)),
+        }
+    }
+
+    fn fields(&self) -> [&Option<String>; 11] {
+        [
+            &self.bill_number,
+            &self.mobile_number,
+            &self.store_label,
+            &self.loyalty_number,
+            &self.reference_label,
+            &self.customer_label,
+            &self.terminal_label,
+            &self.purpose_of_transaction,
+            &self.additional_consumer_data_request,
+            &self.merchant_tax_id,
+            &self.merchant_channel,
+        ]
+    }
+
+    /// Whether no field is set
+    pub fn is_empty(&self) -> bool {
+        self.fields().iter().all(|f| f.is_none()) && self.other.is_empty()
+    }
+
+    /// Check field lengths against the EMVCo specification
+    pub fn validate(&self) -> Result<()> {
+        for ((id, name, max_length), value) in FIELDS.iter().zip(self.fields()) {
+            if let Some(value) = value {
+                let length = value.chars().count();
+                if length == 0 || length > *max_length {
+                    return Err(PromptParseError::InvalidTagValue {
+                        tag_id: format!("{}.{id}", spec::ADDITIONAL_DATA),
+                        rule: format!("{name} must be 1-{max_length} characters, got {length}"),
+                    });
+                }
+            }
+        }
+
+        if let Some(request) = &self.additional_consumer_data_request {
+            if !request.chars().all(|c| matches!(c, 'A' | 'M' | 'E')) {
+                return Err(PromptParseError::InvalidTagValue {
+                    tag_id: format!("{}.09", spec::ADDITIONAL_DATA),
+                    rule: "Additional Consumer Data Request may only contain A, M and E"
+                        .to_string(),
+                });
+            }
+        }
+
+        if let Some(other) = self.other.iter().find(|t| {
+            spec::parse_id(&t.id).map_or(true, |id| id < 12) || t.value.chars().count() > 99
+        }) {
+            return Err(PromptParseError::InvalidTagValue {
+                tag_id: format!("{}.{}", spec::ADDITIONAL_DATA, other.id),
+                rule: "other data objects must use sub-tags 12-99 with at most 99 characters"
+                    .to_string(),
+            });
+        }
+
+        let length = encode(&self.to_tags()).chars().count();
+        if length > 99 {
+            return Err(PromptParseError::InvalidTagValue {
+                tag_id: spec::ADDITIONAL_DATA.to_string(),
+                rule: format!(
+                    "Additional Data Field Template is {length} characters, maximum is 99"
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Sub-tags in ascending ID order
+    pub fn to_tags(&self) -> Vec<TlvTag> {
+        let mut tags: Vec<TlvTag> = FIELDS
+            .iter()
+            .zip(self.fields())
+            .filter_map(|((id, _, _), value)| value.as_deref().map(|v| tag(id, v)))
+            .collect();
+        tags.extend(self.other.iter().cloned());
+        tags
+    }
+
+    /// Build Tag 62, or None if no field is set
+    pub fn to_tag(&self) -> Option<TlvTag> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let sub_tags = self.to_tags();
+        Some(TlvTag::with_sub_tags(
+            spec::ADDITIONAL_DATA.to_string(),
+            encode(&sub_tags),
+            sub_tags,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_additional_data_round_trip() {
+        let data = AdditionalData {
+            bill_number: Some("INV001".to_string()),
+            store_label: Some("***".to_string()),
+            terminal_label: Some("SCB".to_string()),
+            additional_consumer_data_request: Some("ME".to_string()),
+            other: vec![tag("50", "0004TEST")],
+            ..Default::default()
+        };
+
+        let tag62 = data.to_tag().unwrap();
+        assert_eq!(tag62.value, "0106INV0010303***0703SCB0902ME50080004TEST");
+        assert_eq!(AdditionalData::from_tag(&tag62).unwrap(), data);
+        assert_eq!(
+            AdditionalData::from_tag(&tag("62", &tag62.value)).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_additional_data_empty() {
+        assert!(AdditionalData::default().is_empty());
+        assert!(AdditionalData::default().to_tag().is_none());
+    }
+
+    #[test]
+    fn test_additional_data_validate() {
+        let data = AdditionalData {
+            reference_label: Some("A REFERENCE LABEL LONGER THAN 25".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            data.validate(),
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) if tag_id == "62.05"
+        ));
+
+        let data = AdditionalData {
+            additional_consumer_data_request: Some("AX".to_string()),
+            ..Default::default()
+        };
+        assert!(data.validate().is_err());
+
+        let data = AdditionalData {
+            other: vec![tag("05", "DUPLICATE")],
+            ..Default::default()
+        };
+        assert!(data.validate().is_err());
+    }
+}
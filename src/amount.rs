@@ -0,0 +1,460 @@
+use crate::error::PromptParseError;
+use crate::utils::currency::currency_decimals;
+use crate::Result;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// Maximum length of Transaction Amount (Tag 54)
+pub const MAX_AMOUNT_LENGTH: usize = 13;
+
+/// Maximum number of decimal places an [`Amount`] can carry
+const MAX_DECIMALS: u8 = 12;
+
+/// Exact decimal money amount stored as integer minor units
+///
+/// `Amount` never goes through floating point when formatting, so values like
+/// `0.29` stay exactly 29 satang. Amounts with different decimal places but the
+/// same value (e.g. `30` and `30.00`) compare equal.
+///
+/// With the `serde` feature, serialized as a decimal string such as `"30.00"`.
+/// Deserializing accepts that string or a JSON number (read as Baht with 2
+/// decimal places).
+#[derive(Debug, Clone, Copy)]
+pub struct Amount {
+    minor_units: u64,
+    decimals: u8,
+}
+
+impl Amount {
+    /// Create amount from integer minor units
+    ///
+    /// # Arguments
+    /// * `minor_units` - Value in the smallest unit (e.g. satang)
+    /// * `decimals` - Number of decimal places of the minor unit
+    ///
+    /// # Returns
+    /// Amount or error if it does not fit in Tag 54
+    pub fn from_minor_units(minor_units: u64, decimals: u8) -> Result<Self> {
+        if decimals > MAX_DECIMALS {
+            return Err(PromptParseError::AmountPrecisionExceeded {
+                max_decimals: MAX_DECIMALS,
+            });
+        }
+
+        let amount = Self {
+            minor_units,
+            decimals,
+        };
+
+        let length = amount.to_string().len();
+        if length > MAX_AMOUNT_LENGTH {
+            return Err(PromptParseError::AmountTooLong { length });
+        }
+
+        Ok(amount)
+    }
+
+    /// Create Thai Baht amount from satang (1/100 Baht)
+    pub fn from_satang(satang: u64) -> Result<Self> {
+        Self::from_minor_units(satang, 2)
+    }
+
+    /// Create amount from a floating point value
+    ///
+    /// # Arguments
+    /// * `value` - Amount in major units (e.g. Baht)
+    /// * `decimals` - Number of decimal places allowed
+    ///
+    /// # Returns
+    /// Amount or error if the value is negative, not finite, has more decimal
+    /// places than allowed or does not fit in Tag 54
+    pub fn from_f64(value: f64, decimals: u8) -> Result<Self> {
+        if !value.is_finite() || value < 0.0 {
+            return Err(PromptParseError::InvalidAmount);
+        }
+
+        if decimals > MAX_DECIMALS {
+            return Err(PromptParseError::AmountPrecisionExceeded {
+                max_decimals: MAX_DECIMALS,
+            });
+        }
+
+        // Display prints the shortest decimal that round-trips to the same f64,
+        // so its fraction digits are exactly the decimal places of the value
+        let formatted = value.abs().to_string();
+        let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+        if fraction.len() > decimals as usize {
+            return Err(PromptParseError::AmountPrecisionExceeded {
+                max_decimals: decimals,
+            });
+        }
+
+        let digits = format!("{integer}{fraction:0<width$}", width = decimals as usize);
+        let minor_units = digits
+            .parse::<u64>()
+            .map_err(|_| PromptParseError::AmountTooLong {
+                length: integer.len() + usize::from(decimals > 0) + decimals as usize,
+            })?;
+
+        Self::from_minor_units(minor_units, decimals)
+    }
+
+    /// Parse amount string, allowing at most `decimals` decimal places
+    ///
+    /// The result always carries exactly `decimals` decimal places
+    pub fn parse_with_decimals(value: &str, decimals: u8) -> Result<Self> {
+        let amount: Amount = value.parse()?;
+        if amount.decimals > decimals {
+            return Err(PromptParseError::AmountPrecisionExceeded {
+                max_decimals: decimals,
+            });
+        }
+
+        amount.rescale(decimals)
+    }
+
+    /// Number of decimal places of a currency (ISO 4217 numeric or alphabetic code)
+    pub fn currency_decimals(currency: &str) -> u8 {
+        currency_decimals(currency)
+    }
+
+    /// Convert amount to the decimal places used by a currency
+    pub fn for_currency(self, currency: &str) -> Result<Self> {
+        self.rescale(currency_decimals(currency))
+    }
+
+    /// Change the number of decimal places without losing precision
+    ///
+    /// Fails if the amount has non-zero digits past `decimals`, or does not
+    /// fit in Tag 54 after adding decimal places
+    pub fn rescale(self, decimals: u8) -> Result<Self> {
+        match decimals.cmp(&self.decimals) {
+            Ordering::Equal => Ok(self),
+            Ordering::Greater => {
+                let factor = pow10(decimals - self.decimals).ok_or(
+                    PromptParseError::AmountPrecisionExceeded {
+                        max_decimals: MAX_DECIMALS,
+                    },
+                )?;
+                let minor_units = self.minor_units.checked_mul(factor).ok_or(
+                    PromptParseError::AmountTooLong {
+                        length: self.to_string().len() + usize::from(decimals - self.decimals),
+                    },
+                )?;
+                Self::from_minor_units(minor_units, decimals)
+            }
+            Ordering::Less => {
+                let factor = pow10(self.decimals - decimals).unwrap_or(u64::MAX);
+                if self.minor_units % factor != 0 {
+                    return Err(PromptParseError::AmountPrecisionExceeded {
+                        max_decimals: decimals,
+                    });
+                }
+                Self::from_minor_units(self.minor_units / factor, decimals)
+            }
+        }
+    }
+
+    /// Round to `decimals` decimal places (half away from zero)
+    pub fn round_to(self, decimals: u8) -> Self {
+        if decimals >= self.decimals {
+            return self.rescale(decimals).unwrap_or(self);
+        }
+
+        let factor = pow10(self.decimals - decimals).unwrap_or(u64::MAX);
+        let minor_units =
+            self.minor_units / factor + u64::from(self.minor_units % factor >= factor / 2);
+
+        Self {
+            minor_units,
+            decimals,
+        }
+    }
+
+    /// Value in minor units at the amount's own decimal places
+    pub fn minor_units(&self) -> u64 {
+        self.minor_units
+    }
+
+    /// Number of decimal places
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Value in satang if the amount can be represented exactly with 2 decimal places
+    pub fn to_satang(&self) -> Option<u64> {
+        self.rescale(2).ok().map(|amount| amount.minor_units)
+    }
+
+    /// Approximate value as floating point
+    pub fn to_f64(&self) -> f64 {
+        self.minor_units as f64 / 10f64.powi(self.decimals as i32)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.minor_units == 0
+    }
+
+    /// Minor units and decimal places with trailing zeros removed
+    fn normalized(&self) -> (u64, u8) {
+        let mut minor_units = self.minor_units;
+        let mut decimals = self.decimals;
+        while decimals > 0 && minor_units % 10 == 0 {
+            minor_units /= 10;
+            decimals -= 1;
+        }
+        (minor_units, decimals)
+    }
+
+    /// Value scaled to `decimals` decimal places, which must be >= own decimals
+    fn widened(&self, decimals: u8) -> u128 {
+        self.minor_units as u128 * 10u128.pow((decimals - self.decimals) as u32)
+    }
+}
+
+fn pow10(exp: u8) -> Option<u64> {
+    10u64.checked_pow(exp as u32)
+}
+
+impl PartialEq for Amount {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Amount {}
+
+impl PartialOrd for Amount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Amount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let decimals = self.decimals.max(other.decimals);
+        self.widened(decimals).cmp(&other.widened(decimals))
+    }
+}
+
+impl Hash for Amount {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state);
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.decimals == 0 {
+            return write!(f, "{}", self.minor_units);
+        }
+
+        let factor = 10u64.pow(self.decimals as u32);
+        write!(
+            f,
+            "{}.{:0width$}",
+            self.minor_units / factor,
+            self.minor_units % factor,
+            width = self.decimals as usize
+        )
+    }
+}
+
+impl FromStr for Amount {
+    type Err = PromptParseError;
+
+    /// Parse amount string such as `30`, `30.5` or `30.50`
+    ///
+    /// Decimal places are kept as written
+    fn from_str(value: &str) -> Result<Self> {
+        let (integer, fraction) = match value.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (value, ""),
+        };
+
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty()
+            || !is_digits(integer)
+            || !is_digits(fraction)
+            || (value.contains('.') && fraction.is_empty())
+        {
+            return Err(PromptParseError::InvalidAmount);
+        }
+
+        if value.len() > MAX_AMOUNT_LENGTH {
+            return Err(PromptParseError::AmountTooLong {
+                length: value.len(),
+            });
+        }
+
+        if fraction.len() > MAX_DECIMALS as usize {
+            return Err(PromptParseError::AmountPrecisionExceeded {
+                max_decimals: MAX_DECIMALS,
+            });
+        }
+
+        let minor_units = format!("{integer}{fraction}")
+            .parse::<u64>()
+            .map_err(|_| PromptParseError::InvalidAmount)?;
+
+        Self::from_minor_units(minor_units, fraction.len() as u8)
+    }
+}
+
+impl TryFrom<f64> for Amount {
+    type Error = PromptParseError;
+
+    /// Convert Baht value into amount with 2 decimal places
+    fn try_from(value: f64) -> Result<Self> {
+        Self::from_f64(value, 2)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Amount {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Amount {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl serde::de::Visitor<'_> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal amount string or number")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> std::result::Result<Amount, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> std::result::Result<Amount, E> {
+                value
+                    .checked_mul(100)
+                    .ok_or(PromptParseError::InvalidAmount)
+                    .and_then(Amount::from_satang)
+                    .map_err(E::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> std::result::Result<Amount, E> {
+                let value =
+                    u64::try_from(value).map_err(|_| E::custom(PromptParseError::InvalidAmount))?;
+                self.visit_u64(value)
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> std::result::Result<Amount, E> {
+                Amount::try_from(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_from_f64_exact() {
+        let amount = Amount::try_from(0.29).unwrap();
+        assert_eq!(amount.minor_units(), 29);
+        assert_eq!(amount.to_string(), "0.29");
+
+        let amount = Amount::try_from(3649.22).unwrap();
+        assert_eq!(amount.to_satang(), Some(364922));
+    }
+
+    #[test]
+    fn test_amount_rejects_invalid_values() {
+        assert!(matches!(
+            Amount::try_from(-1.0),
+            Err(PromptParseError::InvalidAmount)
+        ));
+        assert!(matches!(
+            Amount::try_from(f64::NAN),
+            Err(PromptParseError::InvalidAmount)
+        ));
+        assert!(matches!(
+            Amount::try_from(1.005),
+            Err(PromptParseError::AmountPrecisionExceeded { max_decimals: 2 })
+        ));
+        for value in [123456.789, 10000.005, 0.1 + 0.2] {
+            assert!(
+                matches!(
+                    Amount::try_from(value),
+                    Err(PromptParseError::AmountPrecisionExceeded { max_decimals: 2 })
+                ),
+                "{value}"
+            );
+        }
+        assert_eq!(
+            Amount::try_from(123456.78).unwrap().to_string(),
+            "123456.78"
+        );
+        assert!(matches!(
+            Amount::try_from(100_000_000_000.0),
+            Err(PromptParseError::AmountTooLong { length: 15 })
+        ));
+    }
+
+    #[test]
+    fn test_amount_large_values() {
+        let amount = Amount::from_satang(220_000_000_000).unwrap();
+        assert_eq!(amount.to_string(), "2200000000.00");
+    }
+
+    #[test]
+    fn test_amount_parse() {
+        let amount: Amount = "20.15".parse().unwrap();
+        assert_eq!(amount.minor_units(), 2015);
+        assert_eq!(amount.decimals(), 2);
+
+        assert!("".parse::<Amount>().is_err());
+        assert!("1.".parse::<Amount>().is_err());
+        assert!(".5".parse::<Amount>().is_err());
+        assert!("-5".parse::<Amount>().is_err());
+        assert!("1e5".parse::<Amount>().is_err());
+        assert!("12345678901234".parse::<Amount>().is_err());
+    }
+
+    #[test]
+    fn test_amount_rescale_and_equality() {
+        let whole: Amount = "30".parse().unwrap();
+        let decimal = Amount::from_satang(3000).unwrap();
+        assert_eq!(whole, decimal);
+        assert_eq!(whole.rescale(2).unwrap().to_string(), "30.00");
+
+        let precise: Amount = "1.005".parse().unwrap();
+        assert!(precise.rescale(2).is_err());
+        assert_eq!(precise.round_to(2).to_string(), "1.01");
+        assert!(Amount::parse_with_decimals("1.005", 2).is_err());
+        assert_eq!(
+            Amount::parse_with_decimals("1.5", 2).unwrap().to_string(),
+            "1.50"
+        );
+    }
+
+    #[test]
+    fn test_amount_for_currency() {
+        let amount: Amount = "10000".parse().unwrap();
+        assert_eq!(amount.for_currency("704").unwrap().to_string(), "10000");
+        assert_eq!(amount.for_currency("764").unwrap().to_string(), "10000.00");
+        assert!(Amount::from_satang(150)
+            .unwrap()
+            .for_currency("VND")
+            .is_err());
+    }
+}
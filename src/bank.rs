@@ -0,0 +1,361 @@
+use crate::error::PromptParseError;
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::discriminant;
+use std::str::FromStr;
+
+/// Thai bank identified by its Bank of Thailand (BOT) 3-digit bank code
+///
+/// Codes that are not in the directory are kept as [`Bank::Other`], so any
+/// code read from a slip can be written back unchanged. Banks compare by their
+/// normalised code, so `Bank::Other("004")` equals [`Bank::Kbank`].
+#[derive(Debug, Clone)]
+pub enum Bank {
+    /// Bangkok Bank (002)
+    Bbl,
+    /// Kasikornbank (004)
+    Kbank,
+    /// Krungthai Bank (006)
+    Ktb,
+    /// TMBThanachart Bank (011)
+    Ttb,
+    /// Siam Commercial Bank (014)
+    Scb,
+    /// Citibank (017)
+    Citi,
+    /// Sumitomo Mitsui Banking Corporation (018)
+    Smbc,
+    /// Standard Chartered Bank (Thai) (020)
+    Scbt,
+    /// CIMB Thai Bank (022)
+    Cimbt,
+    /// United Overseas Bank (Thai) (024)
+    Uobt,
+    /// Bank of Ayudhya (Krungsri) (025)
+    Bay,
+    /// Mega International Commercial Bank (026)
+    Mega,
+    /// Bank of America (027)
+    Boa,
+    /// Government Savings Bank (030)
+    Gsb,
+    /// Hongkong and Shanghai Banking Corporation (031)
+    Hsbc,
+    /// Deutsche Bank (032)
+    Db,
+    /// Government Housing Bank (033)
+    Ghb,
+    /// Bank for Agriculture and Agricultural Cooperatives (034)
+    Baac,
+    /// Export-Import Bank of Thailand (035)
+    Exim,
+    /// Mizuho Bank (039)
+    Mhcb,
+    /// BNP Paribas (045)
+    Bnpp,
+    /// Bank of China (Thai) (052)
+    Boc,
+    /// Islamic Bank of Thailand (066)
+    Ibank,
+    /// TISCO Bank (067)
+    Tisco,
+    /// Kiatnakin Phatra Bank (069)
+    Kkp,
+    /// Industrial and Commercial Bank of China (Thai) (070)
+    Icbct,
+    /// Thai Credit Bank (071)
+    Tcrb,
+    /// Land and Houses Bank (073)
+    Lhb,
+    /// ANZ Bank (Thai) (079)
+    Anz,
+    /// Small and Medium Enterprise Development Bank of Thailand (098)
+    Sme,
+    /// Bank code not in the directory
+    Other(String),
+}
+
+/// Directory entry of a known bank
+struct BankInfo {
+    bank: Bank,
+    code: &'static str,
+    short_code: &'static str,
+    name_en: &'static str,
+    name_th: &'static str,
+    swift: &'static str,
+}
+
+const fn entry(
+    bank: Bank,
+    code: &'static str,
+    short_code: &'static str,
+    name_en: &'static str,
+    name_th: &'static str,
+    swift: &'static str,
+) -> BankInfo {
+    BankInfo {
+        bank,
+        code,
+        short_code,
+        name_en,
+        name_th,
+        swift,
+    }
+}
+
+#[rustfmt::skip]
+const DIRECTORY: &[BankInfo] = &[
+    entry(Bank::Bbl, "002", "BBL", "Bangkok Bank", "ธนาคารกรุงเทพ", "BKKBTHBK"),
+    entry(Bank::Kbank, "004", "KBANK", "Kasikornbank", "ธนาคารกสิกรไทย", "KASITHBK"),
+    entry(Bank::Ktb, "006", "KTB", "Krungthai Bank", "ธนาคารกรุงไทย", "KRTHTHBK"),
+    entry(Bank::Ttb, "011", "TTB", "TMBThanachart Bank", "ธนาคารทหารไทยธนชาต", "TMBKTHBK"),
+    entry(Bank::Scb, "014", "SCB", "Siam Commercial Bank", "ธนาคารไทยพาณิชย์", "SICOTHBK"),
+    entry(Bank::Citi, "017", "CITI", "Citibank", "ธนาคารซิตี้แบงก์", "CITITHBX"),
+    entry(Bank::Smbc, "018", "SMBC", "Sumitomo Mitsui Banking Corporation", "ธนาคารซูมิโตโม มิตซุย แบงกิ้ง คอร์ปอเรชั่น", "SMBCTHBK"),
+    entry(Bank::Scbt, "020", "SCBT", "Standard Chartered Bank (Thai)", "ธนาคารสแตนดาร์ดชาร์เตอร์ด (ไทย)", "SCBLTHBX"),
+    entry(Bank::Cimbt, "022", "CIMBT", "CIMB Thai Bank", "ธนาคารซีไอเอ็มบี ไทย", "UBOBTHBK"),
+    entry(Bank::Uobt, "024", "UOBT", "United Overseas Bank (Thai)", "ธนาคารยูโอบี", "UOVBTHBK"),
+    entry(Bank::Bay, "025", "BAY", "Bank of Ayudhya", "ธนาคารกรุงศรีอยุธยา", "AYUDTHBK"),
+    entry(Bank::Mega, "026", "MEGA", "Mega International Commercial Bank", "ธนาคารเมกะ สากลพาณิชย์", "ICBCTHBK"),
+    entry(Bank::Boa, "027", "BOA", "Bank of America", "ธนาคารแห่งอเมริกา", "BOFATH2X"),
+    entry(Bank::Gsb, "030", "GSB", "Government Savings Bank", "ธนาคารออมสิน", "GSBATHBK"),
+    entry(Bank::Hsbc, "031", "HSBC", "Hongkong and Shanghai Banking Corporation", "ธนาคารฮ่องกงและเซี่ยงไฮ้แบงกิ้งคอร์ปอเรชั่น", "HSBCTHBK"),
+    entry(Bank::Db, "032", "DB", "Deutsche Bank", "ธนาคารดอยซ์แบงก์", "DEUTTHBK"),
+    entry(Bank::Ghb, "033", "GHB", "Government Housing Bank", "ธนาคารอาคารสงเคราะห์", "GOHUTHB1"),
+    entry(Bank::Baac, "034", "BAAC", "Bank for Agriculture and Agricultural Cooperatives", "ธนาคารเพื่อการเกษตรและสหกรณ์การเกษตร", "BAABTHBK"),
+    entry(Bank::Exim, "035", "EXIM", "Export-Import Bank of Thailand", "ธนาคารเพื่อการส่งออกและนำเข้าแห่งประเทศไทย", "EXTHTHBK"),
+    entry(Bank::Mhcb, "039", "MHCB", "Mizuho Bank", "ธนาคารมิซูโฮ", "MHCBTHBK"),
+    entry(Bank::Bnpp, "045", "BNPP", "BNP Paribas", "ธนาคารบีเอ็นพี พารีบาส์", "BNPATHBK"),
+    entry(Bank::Boc, "052", "BOC", "Bank of China (Thai)", "ธนาคารแห่งประเทศจีน (ไทย)", "BKCHTHBK"),
+    entry(Bank::Ibank, "066", "IBANK", "Islamic Bank of Thailand", "ธนาคารอิสลามแห่งประเทศไทย", "TIBTTHBK"),
+    entry(Bank::Tisco, "067", "TISCO", "TISCO Bank", "ธนาคารทิสโก้", "TFPCTHB1"),
+    entry(Bank::Kkp, "069", "KKP", "Kiatnakin Phatra Bank", "ธนาคารเกียรตินาคินภัทร", "KKPBTHBK"),
+    entry(Bank::Icbct, "070", "ICBCT", "Industrial and Commercial Bank of China (Thai)", "ธนาคารไอซีบีซี (ไทย)", "ICBKTHBK"),
+    entry(Bank::Tcrb, "071", "TCRB", "Thai Credit Bank", "ธนาคารไทยเครดิต", "THCETHB1"),
+    entry(Bank::Lhb, "073", "LHB", "Land and Houses Bank", "ธนาคารแลนด์ แอนด์ เฮ้าส์", "LAHRTHB2"),
+    entry(Bank::Anz, "079", "ANZ", "ANZ Bank (Thai)", "ธนาคารเอเอ็นแซด (ไทย)", "ANZBTHBX"),
+    entry(Bank::Sme, "098", "SME", "Small and Medium Enterprise Development Bank of Thailand", "ธนาคารพัฒนาวิสาหกิจขนาดกลางและขนาดย่อมแห่งประเทศไทย", "SMEBTHB1"),
+];
+
+/// Trim surrounding whitespace and pad numeric codes to 3 digits (`14` to `014`)
+fn normalize(code: &str) -> String {
+    let code = code.trim();
+    if !code.is_empty() && code.len() < 3 && code.bytes().all(|b| b.is_ascii_digit()) {
+        format!("{code:0>3}")
+    } else {
+        code.to_string()
+    }
+}
+
+/// Directory entry for a bank code, short code or SWIFT BIC
+fn lookup(code: &str) -> Option<&'static BankInfo> {
+    let code = normalize(code);
+    DIRECTORY.iter().find(|info| {
+        info.code == code
+            || info.short_code.eq_ignore_ascii_case(&code)
+            || info.swift.eq_ignore_ascii_case(&code)
+    })
+}
+
+impl Bank {
+    /// Get bank from its BOT bank code, [`Bank::Other`] if not in the directory
+    ///
+    /// The code is normalised first: surrounding whitespace is trimmed, numeric
+    /// codes are padded to 3 digits, and short codes or SWIFT BICs resolve to
+    /// their bank, so `Bank::from_code(&bank.to_string())` always gives `bank` back.
+    pub fn from_code(code: &str) -> Self {
+        lookup(code).map_or_else(|| Bank::Other(normalize(code)), |info| info.bank.clone())
+    }
+
+    /// Get bank from its short code (e.g. `SCB`) or SWIFT BIC, case-insensitive
+    pub fn from_short_code(short_code: &str) -> Option<Self> {
+        DIRECTORY
+            .iter()
+            .find(|info| {
+                info.short_code.eq_ignore_ascii_case(short_code)
+                    || info.swift.eq_ignore_ascii_case(short_code)
+            })
+            .map(|info| info.bank.clone())
+    }
+
+    /// Every bank in the directory, ordered by bank code
+    pub fn all() -> impl Iterator<Item = Bank> {
+        DIRECTORY.iter().map(|info| info.bank.clone())
+    }
+
+    fn info(&self) -> Option<&'static BankInfo> {
+        match self {
+            Bank::Other(code) => lookup(code),
+            bank => DIRECTORY
+                .iter()
+                .find(|info| discriminant(&info.bank) == discriminant(bank)),
+        }
+    }
+
+    /// Normalised code used for comparison and hashing
+    fn key(&self) -> Cow<'static, str> {
+        match (self.info(), self) {
+            (Some(info), _) => Cow::Borrowed(info.code),
+            (None, Bank::Other(code)) => Cow::Owned(normalize(code)),
+            (None, _) => Cow::Borrowed(""),
+        }
+    }
+
+    /// BOT 3-digit bank code
+    pub fn code(&self) -> &str {
+        match (self.info(), self) {
+            (Some(info), _) => info.code,
+            (None, Bank::Other(code)) => code,
+            (None, _) => "",
+        }
+    }
+
+    /// Short code such as `SCB`, None for codes not in the directory
+    pub fn short_code(&self) -> Option<&'static str> {
+        self.info().map(|info| info.short_code)
+    }
+
+    /// English name, None for codes not in the directory
+    pub fn name_en(&self) -> Option<&'static str> {
+        self.info().map(|info| info.name_en)
+    }
+
+    /// Thai name, None for codes not in the directory
+    pub fn name_th(&self) -> Option<&'static str> {
+        self.info().map(|info| info.name_th)
+    }
+
+    /// SWIFT BIC, None for codes not in the directory
+    pub fn swift(&self) -> Option<&'static str> {
+        self.info().map(|info| info.swift)
+    }
+}
+
+impl PartialEq for Bank {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Bank {}
+
+impl Hash for Bank {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl fmt::Display for Bank {
+    /// Short code for known banks, bank code otherwise
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.short_code().unwrap_or_else(|| self.code()))
+    }
+}
+
+impl FromStr for Bank {
+    type Err = PromptParseError;
+
+    /// Parse a 1-3 digit bank code, short code or SWIFT BIC
+    ///
+    /// Numeric codes not in the directory are kept as [`Bank::Other`]
+    fn from_str(value: &str) -> crate::Result<Self> {
+        let code = value.trim();
+        if (1..=3).contains(&code.len()) && code.bytes().all(|b| b.is_ascii_digit()) {
+            return Ok(Bank::from_code(code));
+        }
+
+        Bank::from_short_code(code)
+            .ok_or_else(|| PromptParseError::ParseError(format!("unknown bank `{value}`")))
+    }
+}
+
+impl From<Bank> for String {
+    fn from(bank: Bank) -> Self {
+        bank.code().to_string()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bank {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bank {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bank_directory() {
+        let scb = Bank::from_code("014");
+        assert_eq!(scb, Bank::Scb);
+        assert_eq!(scb.code(), "014");
+        assert_eq!(scb.short_code(), Some("SCB"));
+        assert_eq!(scb.name_en(), Some("Siam Commercial Bank"));
+        assert_eq!(scb.name_th(), Some("ธนาคารไทยพาณิชย์"));
+        assert_eq!(scb.swift(), Some("SICOTHBK"));
+
+        // Codes and short codes are unique
+        for bank in Bank::all() {
+            assert_eq!(Bank::from_code(bank.code()), bank);
+            assert_eq!(bank.to_string().parse::<Bank>().unwrap(), bank);
+        }
+    }
+
+    #[test]
+    fn test_bank_from_str() {
+        assert_eq!("002".parse::<Bank>().unwrap(), Bank::Bbl);
+        assert_eq!("kbank".parse::<Bank>().unwrap(), Bank::Kbank);
+        assert_eq!("AYUDTHBK".parse::<Bank>().unwrap(), Bank::Bay);
+        assert_eq!("14".parse::<Bank>().unwrap(), Bank::Scb);
+        assert_eq!(" 004 ".parse::<Bank>().unwrap(), Bank::Kbank);
+        assert!("XYZ".parse::<Bank>().is_err());
+        assert!("kbnk".parse::<Bank>().is_err());
+        assert!("0014".parse::<Bank>().is_err());
+        assert!("".parse::<Bank>().is_err());
+    }
+
+    #[test]
+    fn test_bank_unknown_code_round_trip() {
+        let bank: Bank = "099".parse().unwrap();
+        assert_eq!(bank, Bank::Other("099".to_string()));
+        assert_eq!(bank.code(), "099");
+        assert_eq!(bank.to_string(), "099");
+        assert_eq!(bank.name_en(), None);
+        assert_eq!(bank.to_string().parse::<Bank>().unwrap(), bank);
+
+        // Malformed codes read from a slip still round-trip
+        for code in ["9", "99", " 099", "scb"] {
+            let bank = Bank::from_code(code);
+            assert_eq!(bank.to_string().parse::<Bank>().unwrap(), bank);
+            assert_eq!(Bank::from_code(&String::from(bank.clone())), bank);
+        }
+    }
+
+    #[test]
+    fn test_bank_other_equals_known() {
+        use std::collections::HashSet;
+
+        assert_eq!(Bank::Other("004".to_string()), Bank::Kbank);
+        assert_eq!(Bank::Other("4".to_string()).short_code(), Some("KBANK"));
+        assert_ne!(Bank::Other("099".to_string()), Bank::Kbank);
+
+        let banks: HashSet<Bank> = [Bank::Kbank, Bank::Other("004".to_string())].into();
+        assert_eq!(banks.len(), 1);
+    }
+}
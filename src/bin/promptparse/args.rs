@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::io::Read;
+
+use crate::CliError;
+
+/// Options which do not take a value
+const FLAGS: [&str; 6] = ["json", "no-strict", "crc-fix", "repair", "lower", "help"];
+
+/// Parsed command line arguments
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    /// Parse arguments (without the program name)
+    ///
+    /// Options are written as `--name value` or `--name=value`, and `--` ends
+    /// option parsing
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, CliError> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                parsed.positional.extend(args.by_ref());
+                break;
+            }
+
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+
+            if let Some((name, value)) = name.split_once('=') {
+                parsed.options.insert(name.to_string(), value.to_string());
+            } else if FLAGS.contains(&name) {
+                parsed.flags.push(name.to_string());
+            } else {
+                let value = args
+                    .next()
+                    .ok_or_else(|| CliError::Usage(format!("missing value for --{name}")))?;
+                parsed.options.insert(name.to_string(), value);
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Remove and return the next positional argument
+    pub fn next_positional(&mut self) -> Option<String> {
+        if self.positional.is_empty() {
+            None
+        } else {
+            Some(self.positional.remove(0))
+        }
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|f| f == name)
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    pub fn required(&self, name: &str) -> Result<&str, CliError> {
+        self.option(name)
+            .ok_or_else(|| CliError::Usage(format!("missing required option --{name}")))
+    }
+
+    /// Payload from the next positional argument, or stdin if absent or `-`
+    pub fn payload(&mut self) -> Result<String, CliError> {
+        match self.next_positional() {
+            Some(payload) if payload != "-" => Ok(payload),
+            _ => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| CliError::Usage(format!("cannot read stdin: {e}")))?;
+                Ok(input.trim().to_string())
+            }
+        }
+    }
+}
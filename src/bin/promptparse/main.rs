@@ -0,0 +1,309 @@
+//! Command-line tool for generating, parsing and validating PromptPay & EMVCo QR Codes
+
+mod args;
+mod output;
+
+use std::fmt;
+use std::process::ExitCode;
+
+use args::Args;
+use output::Value;
+use promptparse::generate::{
+    self, AnyIdConfig, BillPaymentConfig, BotBarcodeConfig, ProxyType, SlipVerifyConfig,
+    TrueMoneyConfig,
+};
+use promptparse::{
+    checksum, try_parse_repaired, validate, Amount, PromptParseError, Repair, RepairPolicy,
+    Repaired, TlvTag,
+};
+
+const USAGE: &str = "\
+Usage: promptparse <command> [options] [--json]
+
+Commands:
+  gen anyid --type <msisdn|natid|ewallet|bankacc> --target <id> [--amount <amount>]
+  gen bill --biller-id <id> --ref1 <ref> [--ref2 <ref>] [--ref3 <ref>] [--amount <amount>]
+  gen truemoney --mobile <number> [--amount <amount>] [--message <text>]
+  gen slip --bank <code|short code> --trans-ref <ref>
+  gen barcode --biller-id <id> --ref1 <ref> [--ref2 <ref>] [--amount <amount>]
+  parse [payload] [--no-strict] [--repair]
+  validate slip [payload] [--crc-fix] [--repair]
+  validate truemoney-slip [payload]
+  validate bcel [payload]
+  crc [payload] [--tag <id>] [--lower]
+
+Payloads are read from stdin when omitted or `-`. --repair fixes whitespace,
+invisible characters and malformed CRC tags before parsing.
+Output is a table, or JSON with --json.";
+
+#[derive(Debug)]
+pub enum CliError {
+    /// Invalid command line
+    Usage(String),
+    /// Library rejected the input
+    Invalid(PromptParseError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(msg) => write!(f, "{msg}"),
+            CliError::Invalid(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<PromptParseError> for CliError {
+    fn from(err: PromptParseError) -> Self {
+        CliError::Invalid(err)
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => return fail(&err),
+    };
+
+    if args.flag("help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    let json = args.flag("json");
+    match run(&mut args) {
+        Ok(value) if json => {
+            println!("{}", value.to_json());
+            ExitCode::SUCCESS
+        }
+        Ok(value) => {
+            print!("{}", value.to_table());
+            ExitCode::SUCCESS
+        }
+        Err(err) => fail(&err),
+    }
+}
+
+fn fail(err: &CliError) -> ExitCode {
+    eprintln!("error: {err}");
+    match err {
+        CliError::Usage(_) => {
+            eprintln!("\n{USAGE}");
+            ExitCode::from(2)
+        }
+        CliError::Invalid(_) => ExitCode::from(1),
+    }
+}
+
+fn run(args: &mut Args) -> Result<Value, CliError> {
+    let command = args.next_positional();
+    match command.as_deref() {
+        Some("gen") => gen(args),
+        Some("parse") => parse(args),
+        Some("validate") => validate(args),
+        Some("crc") => crc(args),
+        Some(other) => Err(CliError::Usage(format!("unknown command `{other}`"))),
+        None => Err(CliError::Usage("missing command".to_string())),
+    }
+}
+
+fn gen(args: &mut Args) -> Result<Value, CliError> {
+    let kind = args.next_positional();
+    let payload = match kind.as_deref() {
+        Some("anyid") => generate::any_id(AnyIdConfig {
+            proxy_type: proxy_type(args.required("type")?)?,
+            target: args.required("target")?.to_string(),
+            amount: amount(args)?,
+            tip: None,
+            additional_data: None,
+        })?,
+        Some("bill") => generate::bill_payment(BillPaymentConfig {
+            biller_id: args.required("biller-id")?.to_string(),
+            amount: amount(args)?,
+            tip: None,
+            ref1: args.required("ref1")?.to_string(),
+            ref2: args.option("ref2").map(str::to_string),
+            ref3: args.option("ref3").map(str::to_string),
+            additional_data: None,
+        })?,
+        Some("truemoney") => generate::true_money(TrueMoneyConfig {
+            mobile_no: args.required("mobile")?.to_string(),
+            amount: amount(args)?,
+            message: args.option("message").map(str::to_string),
+            additional_data: None,
+        })?,
+        Some("slip") => generate::slip_verify(SlipVerifyConfig::new(
+            args.required("bank")?.parse()?,
+            args.required("trans-ref")?,
+        )),
+        Some("barcode") => generate::bot_barcode(BotBarcodeConfig {
+            biller_id: args.required("biller-id")?.to_string(),
+            ref1: args.required("ref1")?.to_string(),
+            ref2: args.option("ref2").map(str::to_string),
+            amount: amount(args)?,
+        }),
+        Some(other) => return Err(CliError::Usage(format!("unknown QR type `{other}`"))),
+        None => return Err(CliError::Usage("missing QR type".to_string())),
+    };
+
+    Ok(Value::Object(vec![(
+        "payload".to_string(),
+        Value::Str(payload),
+    )]))
+}
+
+fn parse(args: &mut Args) -> Result<Value, CliError> {
+    let payload = args.payload()?;
+    let Repaired { value: qr, repairs } = try_parse_repaired(
+        &payload,
+        !args.flag("no-strict"),
+        true,
+        &repair_policy(args),
+    )?;
+
+    let tags = if args.flag("json") {
+        Value::List(qr.get_tags().iter().map(tag_json).collect())
+    } else {
+        tag_table(qr.get_tags())
+    };
+
+    let mut output = vec![
+        ("payload".to_string(), Value::str(qr.get_payload())),
+        ("tags".to_string(), tags),
+    ];
+    if args.flag("repair") {
+        output.push(("repairs".to_string(), repairs_value(&repairs)));
+    }
+    Ok(Value::Object(output))
+}
+
+fn validate(args: &mut Args) -> Result<Value, CliError> {
+    let kind = args.next_positional();
+    let payload = args.payload()?;
+
+    let fields = match kind.as_deref() {
+        Some("slip") => {
+            let (data, repairs) = if args.flag("repair") {
+                let repaired = validate::try_slip_verify_repaired(&payload, &repair_policy(args))?;
+                (repaired.value, Some(repaired.repairs))
+            } else {
+                let data = validate::try_slip_verify(&payload, args.flag("crc-fix"))?;
+                (data, None)
+            };
+            let bank = data.bank();
+            let mut fields = vec![
+                ("sending_bank", Value::Str(data.sending_bank)),
+                ("bank", Value::opt(bank.name_en())),
+                ("trans_ref", Value::Str(data.trans_ref)),
+            ];
+            if let Some(repairs) = repairs {
+                fields.push(("repairs", repairs_value(&repairs)));
+            }
+            fields
+        }
+        Some("truemoney-slip") => {
+            let data = validate::try_true_money_slip_verify(&payload)?;
+            vec![
+                ("event_type", Value::Str(data.event_type)),
+                ("transaction_id", Value::Str(data.transaction_id)),
+                ("date", Value::Str(data.date)),
+            ]
+        }
+        Some("bcel") => {
+            let data = validate::try_bcel_one_proof(&payload)?;
+            vec![
+                ("type", Value::opt(data.r#type.as_deref())),
+                ("ticket", Value::opt(data.ticket.as_deref())),
+                ("fccref", Value::opt(data.fccref.as_deref())),
+            ]
+        }
+        Some(other) => return Err(CliError::Usage(format!("unknown slip type `{other}`"))),
+        None => return Err(CliError::Usage("missing slip type".to_string())),
+    };
+
+    let mut output = vec![("valid".to_string(), Value::Bool(true))];
+    output.extend(fields.into_iter().map(|(k, v)| (k.to_string(), v)));
+    Ok(Value::Object(output))
+}
+
+fn crc(args: &mut Args) -> Result<Value, CliError> {
+    let payload = args.payload()?;
+    let tag_id = args.option("tag").unwrap_or("63");
+    if tag_id.len() != 2 || !tag_id.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(CliError::Usage(format!(
+            "CRC tag ID must be two digits, got `{tag_id}`"
+        )));
+    }
+
+    let with_header = format!("{payload}{tag_id}04");
+    let crc = checksum(&with_header, !args.flag("lower"));
+
+    Ok(Value::Object(vec![
+        ("crc".to_string(), Value::str(&crc)),
+        (
+            "payload".to_string(),
+            Value::Str(format!("{with_header}{crc}")),
+        ),
+    ]))
+}
+
+/// Every repair with `--repair`, none otherwise
+fn repair_policy(args: &Args) -> RepairPolicy {
+    if args.flag("repair") {
+        RepairPolicy::all()
+    } else {
+        RepairPolicy::new()
+    }
+}
+
+fn repairs_value(repairs: &[Repair]) -> Value {
+    Value::List(
+        repairs
+            .iter()
+            .map(|repair| Value::Str(repair.to_string()))
+            .collect(),
+    )
+}
+
+fn proxy_type(value: &str) -> Result<ProxyType, CliError> {
+    ProxyType::from_name(value).ok_or_else(|| {
+        CliError::Usage(format!(
+            "unknown proxy type `{value}`, expected msisdn, natid, ewallet or bankacc"
+        ))
+    })
+}
+
+fn amount(args: &Args) -> Result<Option<Amount>, CliError> {
+    match args.option("amount") {
+        Some(value) => Ok(Some(Amount::parse_with_decimals(value, 2)?)),
+        None => Ok(None),
+    }
+}
+
+fn tag_json(tag: &TlvTag) -> Value {
+    let sub_tags = match &tag.sub_tags {
+        Some(sub_tags) => Value::List(sub_tags.iter().map(tag_json).collect()),
+        None => Value::Null,
+    };
+
+    Value::Object(vec![
+        ("id".to_string(), Value::str(&tag.id)),
+        ("length".to_string(), Value::Number(tag.length)),
+        ("value".to_string(), Value::str(&tag.value)),
+        ("sub_tags".to_string(), sub_tags),
+    ])
+}
+
+fn tag_table(tags: &[TlvTag]) -> Value {
+    Value::Object(
+        tags.iter()
+            .map(|tag| {
+                let value = match &tag.sub_tags {
+                    Some(sub_tags) => tag_table(sub_tags),
+                    None => Value::str(&tag.value),
+                };
+                (tag.id.clone(), value)
+            })
+            .collect(),
+    )
+}
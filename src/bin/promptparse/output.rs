@@ -0,0 +1,122 @@
+use std::fmt::Write;
+
+/// Command output, printed as JSON or as a human-readable table
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(usize),
+    Str(String),
+    List(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn str(value: &str) -> Self {
+        Value::Str(value.to_string())
+    }
+
+    pub fn opt(value: Option<&str>) -> Self {
+        value.map_or(Value::Null, Value::str)
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out);
+        out
+    }
+
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => out.push_str(&n.to_string()),
+            Value::Str(s) => write_json_string(s, out),
+            Value::List(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.write_json(out);
+                }
+                out.push(']');
+            }
+            Value::Object(fields) => {
+                out.push('{');
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_json_string(key, out);
+                    out.push(':');
+                    value.write_json(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    /// Render as aligned `key  value` rows, nesting lists and objects
+    pub fn to_table(&self) -> String {
+        let mut out = String::new();
+        self.write_table(0, &mut out);
+        out
+    }
+
+    fn write_table(&self, indent: usize, out: &mut String) {
+        match self {
+            Value::Object(fields) => {
+                let width = fields.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+                for (key, value) in fields {
+                    match value {
+                        Value::List(_) | Value::Object(_) => {
+                            let _ = writeln!(out, "{:indent$}{key}:", "");
+                            value.write_table(indent + 2, out);
+                        }
+                        _ => {
+                            let _ =
+                                writeln!(out, "{:indent$}{key:<width$}  {}", "", value.scalar());
+                        }
+                    }
+                }
+            }
+            Value::List(items) => {
+                for item in items {
+                    item.write_table(indent, out);
+                }
+            }
+            _ => {
+                let _ = writeln!(out, "{:indent$}{}", "", self.scalar());
+            }
+        }
+    }
+
+    fn scalar(&self) -> String {
+        match self {
+            Value::Null => "-".to_string(),
+            Value::Bool(b) => if *b { "yes" } else { "no" }.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Str(s) => s.clone(),
+            _ => self.to_json(),
+        }
+    }
+}
+
+fn write_json_string(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -1,18 +1,26 @@
+use crate::amount::Amount;
 use crate::generate::bill_payment;
 use crate::generate::BillPaymentConfig;
+use crate::PromptParseError;
 use crate::Result;
 use std::fmt;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BotBarcode {
     pub biller_id: String,
     pub ref1: String,
     pub ref2: Option<String>,
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
 }
 
 impl BotBarcode {
-    pub fn new(biller_id: String, ref1: String, ref2: Option<String>, amount: Option<f64>) -> Self {
+    pub fn new(
+        biller_id: String,
+        ref1: String,
+        ref2: Option<String>,
+        amount: Option<Amount>,
+    ) -> Self {
         Self {
             biller_id,
             ref1,
@@ -22,13 +30,21 @@ impl BotBarcode {
     }
 
     pub fn from_string(payload: &str) -> Option<Self> {
-        if !payload.starts_with('|') {
-            return None;
-        }
+        Self::try_from_string(payload).ok()
+    }
+
+    /// Same as [`BotBarcode::from_string`] but reports why the payload was rejected
+    pub fn try_from_string(payload: &str) -> Result<Self> {
+        let data = payload
+            .strip_prefix('|')
+            .ok_or(PromptParseError::InvalidBarcode)?;
 
-        let data: Vec<&str> = payload[1..].split('\r').collect();
+        let data: Vec<&str> = data.split('\r').collect();
         if data.len() != 4 {
-            return None;
+            return Err(PromptParseError::InvalidTagValue {
+                tag_id: "barcode".to_string(),
+                rule: format!("expected 4 fields separated by CR, found {}", data.len()),
+            });
         }
 
         let biller_id = data[0].to_string();
@@ -42,10 +58,13 @@ impl BotBarcode {
         let amount = if data[3] == "0" {
             None
         } else {
-            data[3].parse::<i32>().ok().map(|amt| (amt as f64) / 100.0)
+            let satang = data[3]
+                .parse::<u64>()
+                .map_err(|_| PromptParseError::InvalidAmount)?;
+            Some(Amount::from_satang(satang)?)
         };
 
-        Some(Self::new(biller_id, ref1, ref2, amount))
+        Ok(Self::new(biller_id, ref1, ref2, amount))
     }
 
     /// Converts BOT Barcode to PromptPay QR Tag 30 (Bill Payment)
@@ -55,20 +74,23 @@ impl BotBarcode {
         let config = BillPaymentConfig {
             biller_id: self.biller_id.clone(),
             amount: self.amount,
+            tip: None,
             ref1: self.ref1.clone(),
             ref2: self.ref2.clone(),
             ref3: None,
+            additional_data: None,
         };
 
         bill_payment(config)
     }
 }
 
+/// Amounts with more than 2 decimal places are rounded to the nearest satang
 impl fmt::Display for BotBarcode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let amount_str = self
             .amount
-            .map(|amt| ((amt * 100.0) as i32).to_string())
+            .map(|amt| amt.round_to(2).minor_units().to_string())
             .unwrap_or_else(|| "0".to_string());
 
         write!(
@@ -105,7 +127,7 @@ mod tests {
         assert_eq!(barcode.biller_id, "099400016550100");
         assert_eq!(barcode.ref1, "123456789012");
         assert_eq!(barcode.ref2, Some("670429".to_string()));
-        assert_eq!(barcode.amount, Some(3649.22));
+        assert_eq!(barcode.amount, Some(Amount::try_from(3649.22).unwrap()));
     }
 
     #[test]
@@ -120,9 +142,47 @@ mod tests {
         assert_eq!(barcode.to_string(), "|099999999999990\r111222333444\r\r0");
     }
 
+    #[test]
+    fn test_bot_barcode_to_string_exact_amount() {
+        let barcode = BotBarcode::new(
+            "099999999999990".to_string(),
+            "111222333444".to_string(),
+            None,
+            Some(Amount::try_from(0.29).unwrap()),
+        );
+        assert_eq!(barcode.to_string(), "|099999999999990\r111222333444\r\r29");
+
+        let barcode = BotBarcode::new(
+            "099999999999990".to_string(),
+            "111222333444".to_string(),
+            None,
+            Some(Amount::try_from(50_000_000.0).unwrap()),
+        );
+        assert_eq!(
+            barcode.to_string(),
+            "|099999999999990\r111222333444\r\r5000000000"
+        );
+    }
+
     #[test]
     fn test_bot_barcode_invalid_format() {
         assert!(BotBarcode::from_string("invalid").is_none());
         assert!(BotBarcode::from_string("|too\rfew\rparts").is_none());
     }
+
+    #[test]
+    fn test_bot_barcode_try_from_string_errors() {
+        assert!(matches!(
+            BotBarcode::try_from_string("invalid"),
+            Err(PromptParseError::InvalidBarcode)
+        ));
+        assert!(matches!(
+            BotBarcode::try_from_string("|too\rfew\rparts"),
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+        assert!(matches!(
+            BotBarcode::try_from_string("|0999\r111\r\rABC"),
+            Err(PromptParseError::InvalidAmount)
+        ));
+    }
 }
@@ -0,0 +1,127 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::generate::duitnow::{DuitNowConfig, DUITNOW_AID};
+use crate::promptpay::template;
+use crate::tlv::get_tag;
+
+/// Malaysia DuitNow QR data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DuitNowQr {
+    /// PayNet participant code of the acquiring bank or wallet (Tag 26.01)
+    pub acquirer_id: String,
+    /// Merchant ID or account assigned by the acquirer (Tag 26.02)
+    pub merchant_id: String,
+    /// Merchant Category Code (Tag 52)
+    pub merchant_category_code: String,
+    /// Merchant name (Tag 59)
+    pub merchant_name: String,
+    /// Merchant city (Tag 60)
+    pub merchant_city: String,
+    /// Postal code (Tag 61)
+    pub postal_code: Option<String>,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Currency code (ISO 4217 numeric)
+    pub currency: String,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+impl DuitNowQr {
+    /// Read the DuitNow fields of a parsed EMVCo QR
+    ///
+    /// Returns None if Tag 26 is not a DuitNow template, or the merchant name
+    /// or city is missing
+    pub fn from_emvco(qr: &EmvCoQr) -> Option<Self> {
+        let tag26 = template(qr, "26")?;
+        if get_tag(&tag26, "00", None)?.value != DUITNOW_AID {
+            return None;
+        }
+
+        let amount = match qr.get_tag_value("54", None) {
+            Some(value) => Some(value.parse::<Amount>().ok()?),
+            None => None,
+        };
+        let value = |tag_id: &str| qr.get_tag_value(tag_id, None).map(str::to_string);
+
+        Some(DuitNowQr {
+            acquirer_id: get_tag(&tag26, "01", None)?.value.clone(),
+            merchant_id: get_tag(&tag26, "02", None)?.value.clone(),
+            merchant_category_code: value("52")?,
+            merchant_name: value("59")?,
+            merchant_city: value("60")?,
+            postal_code: value("61"),
+            amount,
+            currency: value("53")?,
+            is_dynamic: qr.get_tag_value("01", None) == Some("12"),
+            additional_data: qr.additional_data(),
+        })
+    }
+
+    /// Convert back into a config accepted by [`crate::generate::duitnow()`]
+    pub fn to_config(&self) -> DuitNowConfig {
+        DuitNowConfig {
+            acquirer_id: self.acquirer_id.clone(),
+            merchant_id: self.merchant_id.clone(),
+            merchant_category_code: self.merchant_category_code.clone(),
+            merchant_name: self.merchant_name.clone(),
+            merchant_city: self.merchant_city.clone(),
+            postal_code: self.postal_code.clone(),
+            amount: self.amount,
+            additional_data: self.additional_data.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::duitnow;
+    use crate::parser::parse;
+
+    // Fixed payloads with their CRCs, laid out per the DuitNow QR specification.
+    // They are not PayNet-published samples; those go in
+    // tests/fixtures/duitnow_published.txt, checked by the integration tests
+    const STATIC_SAMPLE: &str =
+        "00020101021126480014A00000061500010106890053021600000000000123455204\
+        581253034585802MY5910KEDAI KOPI6012KUALA LUMPUR61055045062110507TABLE1263042C87";
+    const DYNAMIC_SAMPLE: &str =
+        "00020101021226480014A00000061500010106890053021600000000000123455204\
+        58125303458540512.305802MY5910KEDAI KOPI6012KUALA LUMPUR62110107INV00016304C7C1";
+
+    #[test]
+    fn test_duitnow_sample_round_trip() {
+        let payload = STATIC_SAMPLE;
+        let qr = DuitNowQr::from_emvco(&parse(payload, true, true).unwrap()).unwrap();
+        assert_eq!(qr.acquirer_id, "890053");
+        assert_eq!(qr.merchant_id, "0000000000012345");
+        assert_eq!(qr.merchant_city, "KUALA LUMPUR");
+        assert_eq!(qr.postal_code.as_deref(), Some("50450"));
+        assert_eq!(
+            qr.additional_data
+                .as_ref()
+                .unwrap()
+                .reference_label
+                .as_deref(),
+            Some("TABLE12")
+        );
+        assert!(!qr.is_dynamic);
+        assert_eq!(duitnow(qr.to_config()).unwrap(), payload);
+
+        let payload = DYNAMIC_SAMPLE;
+        let qr = DuitNowQr::from_emvco(&parse(payload, true, true).unwrap()).unwrap();
+        assert_eq!(qr.amount, Some("12.30".parse().unwrap()));
+        assert!(qr.is_dynamic);
+        assert_eq!(duitnow(qr.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_duitnow_not_duitnow() {
+        let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+        assert!(DuitNowQr::from_emvco(&parse(payload, true, true).unwrap()).is_none());
+    }
+}
@@ -1,11 +1,51 @@
-use crate::tlv::{encode, get_tag, with_crc_tag, TlvTag};
+use crate::additional_data::AdditionalData;
+use crate::error::PromptParseError;
+use crate::spec;
+use crate::tlv::{encode, get_tag, get_tag_ref, with_crc_tag, TlvTag, TlvTagRef};
+use crate::validate::conformance::{conformance, Finding};
+use crate::Result;
 
-#[derive(Debug, Clone)]
+/// Parsed EMVCo QR Code
+///
+/// With the `serde` feature, serialized as `{"payload": ..., "tags": [...]}` where
+/// each tag is a [`TlvTag`] with `id`, `value`, `sub_tags` and `length`.
+/// Deserializing checks that `tags` encode back to `payload`, so QRs parsed
+/// without CRC validation or with a custom schema round-trip unchanged. If
+/// `tags` is omitted, `payload` is parsed with the EMVCo schema and no CRC check
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "EmvCoQrData"))]
 pub struct EmvCoQr {
     payload: String,
     tags: Vec<TlvTag>,
 }
 
+/// Unchecked [`EmvCoQr`] fields read by the deserializer
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct EmvCoQrData {
+    payload: String,
+    #[serde(default)]
+    tags: Option<Vec<TlvTag>>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<EmvCoQrData> for EmvCoQr {
+    type Error = PromptParseError;
+
+    fn try_from(data: EmvCoQrData) -> Result<Self> {
+        let Some(tags) = data.tags else {
+            return crate::parser::try_parse(&data.payload, false, true);
+        };
+        if encode(&tags) != data.payload {
+            return Err(PromptParseError::ParseError(
+                "tags do not match payload".to_string(),
+            ));
+        }
+        Ok(EmvCoQr::new(data.payload, tags))
+    }
+}
+
 impl EmvCoQr {
     pub fn new(payload: String, tags: Vec<TlvTag>) -> Self {
         Self { payload, tags }
@@ -20,6 +60,22 @@ impl EmvCoQr {
             .map(|tag| tag.value.as_str())
     }
 
+    /// Same as [`EmvCoQr::get_tag_value`] but returns [`PromptParseError::TagNotFound`]
+    /// with the tag path (e.g. `29.01`) when the tag is missing
+    pub fn try_get_tag_value(&self, tag_id: &str, sub_tag_id: Option<&str>) -> Result<&str> {
+        self.get_tag_value(tag_id, sub_tag_id).ok_or_else(|| {
+            PromptParseError::TagNotFound(match sub_tag_id {
+                Some(sub_id) => format!("{tag_id}.{sub_id}"),
+                None => tag_id.to_string(),
+            })
+        })
+    }
+
+    /// Additional Data Field Template (Tag 62), or None if missing or malformed
+    pub fn additional_data(&self) -> Option<AdditionalData> {
+        AdditionalData::from_tag(self.get_tag(spec::ADDITIONAL_DATA, None)?).ok()
+    }
+
     pub fn get_tags(&self) -> &[TlvTag] {
         &self.tags
     }
@@ -39,6 +95,54 @@ impl EmvCoQr {
         let expected = with_crc_tag(&encode(&tags), crc_tag_id, true);
         self.payload == expected
     }
+
+    /// Check the QR against the EMVCo Merchant-Presented Mode specification
+    ///
+    /// Unlike [`EmvCoQr::validate`] which only checks the CRC, this reports
+    /// every violation found (see [`crate::validate::conformance()`])
+    pub fn lint(&self) -> Vec<Finding> {
+        conformance(self)
+    }
+}
+
+/// Borrowed EMVCo QR referencing slices of the parsed payload
+///
+/// Returned by [`crate::parser::parse_ref`], convert with [`EmvCoQrRef::to_owned`]
+/// to keep the data beyond the lifetime of the payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmvCoQrRef<'a> {
+    payload: &'a str,
+    tags: Vec<TlvTagRef<'a>>,
+}
+
+impl<'a> EmvCoQrRef<'a> {
+    pub fn new(payload: &'a str, tags: Vec<TlvTagRef<'a>>) -> Self {
+        Self { payload, tags }
+    }
+
+    pub fn get_tag(&self, tag_id: &str, sub_tag_id: Option<&str>) -> Option<&TlvTagRef<'a>> {
+        get_tag_ref(&self.tags, tag_id, sub_tag_id)
+    }
+
+    pub fn get_tag_value(&self, tag_id: &str, sub_tag_id: Option<&str>) -> Option<&'a str> {
+        self.get_tag(tag_id, sub_tag_id).map(|tag| tag.value)
+    }
+
+    pub fn get_tags(&self) -> &[TlvTagRef<'a>] {
+        &self.tags
+    }
+
+    pub fn get_payload(&self) -> &'a str {
+        self.payload
+    }
+
+    /// Copy into an owned [`EmvCoQr`]
+    pub fn to_owned(&self) -> EmvCoQr {
+        EmvCoQr::new(
+            self.payload.to_string(),
+            self.tags.iter().map(TlvTagRef::to_owned).collect(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -58,6 +162,17 @@ mod tests {
         assert_eq!(qr.get_tag_value("01", None), Some("11"));
     }
 
+    #[test]
+    fn test_emvco_qr_try_get_tag_value() {
+        let qr = EmvCoQr::new("000201".to_string(), vec![tag("00", "01")]);
+
+        assert_eq!(qr.try_get_tag_value("00", None).unwrap(), "01");
+        assert!(matches!(
+            qr.try_get_tag_value("29", Some("01")),
+            Err(PromptParseError::TagNotFound(path)) if path == "29.01"
+        ));
+    }
+
     #[test]
     fn test_emvco_qr_validation() {
         let tags = vec![
@@ -6,14 +6,45 @@ pub enum PromptParseError {
     InvalidPayload,
     InvalidChecksum,
     InvalidTlv,
+    /// TLV data is malformed at the given byte offset
+    InvalidTlvAt {
+        offset: usize,
+        reason: String,
+    },
+    /// Tag length points past the end of the payload
+    TagLengthOverflow {
+        offset: usize,
+        tag_id: String,
+        length: usize,
+    },
+    /// Checksum in payload does not match the calculated one
+    ChecksumMismatch {
+        expected: String,
+        actual: String,
+    },
+    /// Tag value does not satisfy a format rule
+    InvalidTagValue {
+        tag_id: String,
+        rule: String,
+    },
     TagNotFound(String),
     InvalidProxyType,
     InvalidAmount,
+    /// Amount has more decimal places than allowed
+    AmountPrecisionExceeded {
+        max_decimals: u8,
+    },
+    /// Amount does not fit in Transaction Amount (Tag 54)
+    AmountTooLong {
+        length: usize,
+    },
     InvalidMobileNumber,
     MissingRequiredField(String),
     InvalidBarcode,
     ParseError(String),
     EncodingError(String),
+    /// Image could not be read or decoded
+    InvalidImage(String),
 }
 
 impl fmt::Display for PromptParseError {
@@ -22,9 +53,32 @@ impl fmt::Display for PromptParseError {
             PromptParseError::InvalidPayload => write!(f, "Invalid payload format"),
             PromptParseError::InvalidChecksum => write!(f, "Invalid checksum"),
             PromptParseError::InvalidTlv => write!(f, "Invalid TLV data"),
+            PromptParseError::InvalidTlvAt { offset, reason } => {
+                write!(f, "Invalid TLV data at offset {offset}: {reason}")
+            }
+            PromptParseError::TagLengthOverflow {
+                offset,
+                tag_id,
+                length,
+            } => write!(
+                f,
+                "Tag {tag_id} at offset {offset} has length {length} which exceeds the payload"
+            ),
+            PromptParseError::ChecksumMismatch { expected, actual } => {
+                write!(f, "Checksum mismatch: expected {expected}, got {actual}")
+            }
+            PromptParseError::InvalidTagValue { tag_id, rule } => {
+                write!(f, "Invalid value in tag {tag_id}: {rule}")
+            }
             PromptParseError::TagNotFound(tag) => write!(f, "Tag not found: {tag}"),
             PromptParseError::InvalidProxyType => write!(f, "Invalid proxy type"),
             PromptParseError::InvalidAmount => write!(f, "Invalid amount format"),
+            PromptParseError::AmountPrecisionExceeded { max_decimals } => {
+                write!(f, "Amount has more than {max_decimals} decimal places")
+            }
+            PromptParseError::AmountTooLong { length } => {
+                write!(f, "Amount is {length} characters long, maximum is 13")
+            }
             PromptParseError::InvalidMobileNumber => write!(f, "Invalid mobile number format"),
             PromptParseError::MissingRequiredField(field) => {
                 write!(f, "Missing required field: {field}")
@@ -32,6 +86,7 @@ impl fmt::Display for PromptParseError {
             PromptParseError::InvalidBarcode => write!(f, "Invalid barcode format"),
             PromptParseError::ParseError(msg) => write!(f, "Parse error: {msg}"),
             PromptParseError::EncodingError(msg) => write!(f, "Encoding error: {msg}"),
+            PromptParseError::InvalidImage(msg) => write!(f, "Invalid image: {msg}"),
         }
     }
 }
@@ -1,7 +1,15 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::error::PromptParseError;
+use crate::generate::tip::TipPolicy;
 use crate::tlv::{encode, tag, with_crc_tag};
 use crate::Result;
 
-#[derive(Debug, Clone)]
+/// PromptPay proxy type
+///
+/// With the `serde` feature, serialized as its [`ProxyType::name`]. Deserializing
+/// also accepts the Tag 29 sub-tag ID, either as a string (`"01"`) or a number (`1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProxyType {
     /// Mobile number
     Msisdn,
@@ -14,7 +22,8 @@ pub enum ProxyType {
 }
 
 impl ProxyType {
-    fn to_code(&self) -> &'static str {
+    /// Sub-tag ID of this proxy type in Tag 29
+    pub fn to_code(&self) -> &'static str {
         match self {
             ProxyType::Msisdn => "01",
             ProxyType::NatId => "02",
@@ -22,26 +31,193 @@ impl ProxyType {
             ProxyType::BankAcc => "04",
         }
     }
+
+    /// Get proxy type from Tag 29 sub-tag ID
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "01" => Some(ProxyType::Msisdn),
+            "02" => Some(ProxyType::NatId),
+            "03" => Some(ProxyType::EWalletId),
+            "04" => Some(ProxyType::BankAcc),
+            _ => None,
+        }
+    }
+
+    /// Human-readable name: `msisdn`, `natid`, `ewallet` or `bankacc`
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProxyType::Msisdn => "msisdn",
+            ProxyType::NatId => "natid",
+            ProxyType::EWalletId => "ewallet",
+            ProxyType::BankAcc => "bankacc",
+        }
+    }
+
+    /// Get proxy type from its name (case-insensitive, `mobile` and
+    /// `ewalletid` are also accepted)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "msisdn" | "mobile" => Some(ProxyType::Msisdn),
+            "natid" => Some(ProxyType::NatId),
+            "ewallet" | "ewalletid" => Some(ProxyType::EWalletId),
+            "bankacc" => Some(ProxyType::BankAcc),
+            _ => None,
+        }
+    }
+
+    /// Check that `target` is a well-formed recipient for this proxy type
+    ///
+    /// - Mobile number: Thai mobile number with a `06`, `08` or `09` prefix, either
+    ///   local (`0812223333`) or international (`66812223333`, `0066812223333`)
+    /// - National ID / Tax ID: 13 digits with a valid mod-11 check digit
+    /// - E-Wallet ID: 15 digits
+    /// - Bank account: 3-digit bank code followed by a 1-40 digit account number
+    ///
+    /// # Returns
+    /// `InvalidMobileNumber` for a bad mobile number, `InvalidProxyType` for any
+    /// other target that does not fit its proxy type
+    pub fn validate_target(&self, target: &str) -> Result<()> {
+        let valid = match self {
+            ProxyType::Msisdn => is_mobile_number(target),
+            ProxyType::NatId => is_national_id(target),
+            ProxyType::EWalletId => target.len() == 15 && is_digits(target),
+            ProxyType::BankAcc => (4..=43).contains(&target.len()) && is_digits(target),
+        };
+
+        match (valid, self) {
+            (true, _) => Ok(()),
+            (false, ProxyType::Msisdn) => Err(PromptParseError::InvalidMobileNumber),
+            (false, _) => Err(PromptParseError::InvalidProxyType),
+        }
+    }
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Local Thai mobile number, e.g. `0812223333`
+pub(crate) fn is_local_mobile_number(value: &str) -> bool {
+    value.len() == 10 && is_digits(value) && matches!(&value[..2], "06" | "08" | "09")
+}
+
+/// Thai mobile number in local, `66` or `0066` form
+fn is_mobile_number(value: &str) -> bool {
+    let national = value
+        .strip_prefix("0066")
+        .or_else(|| value.strip_prefix("66"))
+        .map(|rest| format!("0{rest}"));
+    is_local_mobile_number(national.as_deref().unwrap_or(value))
+}
+
+/// 13-digit Thai National ID or Tax ID with a valid check digit
+///
+/// The check digit is `(11 - sum % 11) % 10`, where `sum` weighs the first 12
+/// digits from 13 down to 2.
+pub fn is_national_id(id: &str) -> bool {
+    if id.len() != 13 || !is_digits(id) {
+        return false;
+    }
+
+    let digits: Vec<u32> = id.bytes().map(|b| u32::from(b - b'0')).collect();
+    let sum: u32 = digits[..12]
+        .iter()
+        .zip((2..=13).rev())
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    (11 - sum % 11) % 10 == digits[12]
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ProxyType {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ProxyType {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct ProxyTypeVisitor;
+
+        impl serde::de::Visitor<'_> for ProxyTypeVisitor {
+            type Value = ProxyType;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a proxy type name or Tag 29 sub-tag ID")
+            }
+
+            fn visit_str<E: serde::de::Error>(
+                self,
+                value: &str,
+            ) -> std::result::Result<ProxyType, E> {
+                ProxyType::from_name(value)
+                    .or_else(|| ProxyType::from_code(value))
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_u64<E: serde::de::Error>(
+                self,
+                value: u64,
+            ) -> std::result::Result<ProxyType, E> {
+                ProxyType::from_code(&format!("{value:02}"))
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(
+                self,
+                value: i64,
+            ) -> std::result::Result<ProxyType, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+                    .and_then(|value| self.visit_u64(value))
+            }
+        }
+
+        deserializer.deserialize_any(ProxyTypeVisitor)
+    }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnyIdConfig {
     /// Proxy type
     pub proxy_type: ProxyType,
     /// Recipient number
     pub target: String,
     /// Transaction amount
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub tip: Option<TipPolicy>,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
 }
 
 /// Generate PromptPay AnyID (Tag 29) QR Code
+///
+/// The target is checked with [`ProxyType::validate_target`] first, use
+/// [`any_id_unchecked`] to skip the check.
 pub fn any_id(config: AnyIdConfig) -> Result<String> {
+    config.proxy_type.validate_target(&config.target)?;
+    any_id_unchecked(config)
+}
+
+/// Generate PromptPay AnyID (Tag 29) QR Code without validating the target
+pub fn any_id_unchecked(config: AnyIdConfig) -> Result<String> {
     let mut target = config.target;
 
     if matches!(config.proxy_type, ProxyType::Msisdn) {
-        // Convert mobile number format: remove leading 0, add 66, pad to 13 digits
-        if target.starts_with('0') {
-            target = format!("66{}", &target[1..]);
+        // Convert mobile number format: drop the 00 international prefix or
+        // replace the leading 0 with 66, then pad to 13 digits
+        if let Some(rest) = target.strip_prefix("0066") {
+            target = format!("66{rest}");
+        } else if let Some(rest) = target.strip_prefix('0') {
+            target = format!("66{rest}");
         }
         target = format!("{target:0>13}");
     }
@@ -60,7 +236,16 @@ pub fn any_id(config: AnyIdConfig) -> Result<String> {
     ];
 
     if let Some(amount) = config.amount {
-        payload.push(tag("54", &format!("{amount:.2}")));
+        payload.push(tag("54", &amount.rescale(2)?.to_string()));
+    }
+
+    if let Some(tip) = &config.tip {
+        payload.extend(tip.to_tags(2)?);
+    }
+
+    if let Some(additional_data) = &config.additional_data {
+        additional_data.validate()?;
+        payload.extend(additional_data.to_tag());
     }
 
     Ok(with_crc_tag(&encode(&payload), "63", true))
@@ -76,6 +261,8 @@ mod tests {
             proxy_type: ProxyType::Msisdn,
             target: "0812223333".to_string(),
             amount: None,
+            tip: None,
+            additional_data: None,
         };
         let result = any_id(config).unwrap();
         assert_eq!(
@@ -89,7 +276,9 @@ mod tests {
         let config = AnyIdConfig {
             proxy_type: ProxyType::Msisdn,
             target: "0812223333".to_string(),
-            amount: Some(30.0),
+            amount: Some(Amount::try_from(30.0).unwrap()),
+            tip: None,
+            additional_data: None,
         };
         let result = any_id(config).unwrap();
         assert_eq!(
@@ -102,11 +291,116 @@ mod tests {
     fn test_any_id_natid() {
         let config = AnyIdConfig {
             proxy_type: ProxyType::NatId,
-            target: "1234567890123".to_string(),
+            target: "1234567890121".to_string(),
             amount: None,
+            tip: None,
+            additional_data: None,
         };
         let result = any_id(config).unwrap();
         // Just verify it doesn't error and produces a result
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn test_any_id_with_additional_data() {
+        let additional_data = AdditionalData {
+            bill_number: Some("INV001".to_string()),
+            additional_consumer_data_request: Some("M".to_string()),
+            ..Default::default()
+        };
+        let config = AnyIdConfig {
+            proxy_type: ProxyType::Msisdn,
+            target: "0812223333".to_string(),
+            amount: None,
+            tip: None,
+            additional_data: Some(additional_data.clone()),
+        };
+        let result = any_id(config).unwrap();
+        assert!(result.contains("62150106INV0010901M"));
+
+        let qr = crate::parser::parse(&result, true, true).unwrap();
+        assert_eq!(qr.additional_data(), Some(additional_data));
+    }
+
+    #[test]
+    fn test_validate_target() {
+        for mobile in ["0812223333", "0612223333", "66912223333", "0066812223333"] {
+            assert!(
+                ProxyType::Msisdn.validate_target(mobile).is_ok(),
+                "{mobile}"
+            );
+        }
+        for mobile in ["081222333", "0212223333", "08122233334", "08-1222-3333"] {
+            assert!(matches!(
+                ProxyType::Msisdn.validate_target(mobile),
+                Err(PromptParseError::InvalidMobileNumber)
+            ));
+        }
+
+        assert!(is_national_id("1234567890121"));
+        assert!(is_national_id("0105536112014"));
+        assert!(!is_national_id("1234567890123"));
+        assert!(matches!(
+            ProxyType::NatId.validate_target("1234567890123"),
+            Err(PromptParseError::InvalidProxyType)
+        ));
+
+        assert!(ProxyType::EWalletId
+            .validate_target("140000812223333")
+            .is_ok());
+        assert!(ProxyType::EWalletId
+            .validate_target("14000081222333")
+            .is_err());
+        assert!(ProxyType::BankAcc.validate_target("0141234567890").is_ok());
+        assert!(ProxyType::BankAcc.validate_target("014-123456").is_err());
+    }
+
+    #[test]
+    fn test_any_id_mobile_forms() {
+        for mobile in ["0812223333", "66812223333", "0066812223333"] {
+            let config = AnyIdConfig {
+                proxy_type: ProxyType::Msisdn,
+                target: mobile.to_string(),
+                amount: None,
+                tip: None,
+                additional_data: None,
+            };
+            assert_eq!(
+                any_id(config).unwrap(),
+                "00020101021129370016A000000677010111011300668122233335303764\
+                 5802TH63041DCF",
+                "{mobile}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_any_id_unchecked() {
+        let config = AnyIdConfig {
+            proxy_type: ProxyType::Msisdn,
+            target: "081222333".to_string(),
+            amount: None,
+            tip: None,
+            additional_data: None,
+        };
+        assert!(matches!(
+            any_id(config.clone()),
+            Err(PromptParseError::InvalidMobileNumber)
+        ));
+        assert!(any_id_unchecked(config).unwrap().contains("0006681222333"));
+    }
+
+    #[test]
+    fn test_proxy_type_name() {
+        for proxy_type in [
+            ProxyType::Msisdn,
+            ProxyType::NatId,
+            ProxyType::EWalletId,
+            ProxyType::BankAcc,
+        ] {
+            assert_eq!(ProxyType::from_name(proxy_type.name()), Some(proxy_type));
+        }
+        assert_eq!(ProxyType::from_name("Mobile"), Some(ProxyType::Msisdn));
+        assert_eq!(ProxyType::from_name("01"), None);
+    }
 }
@@ -1,18 +1,28 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::generate::tip::TipPolicy;
 use crate::tlv::{encode, tag, with_crc_tag};
 use crate::Result;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BillPaymentConfig {
     /// Biller ID (National ID or Tax ID + Suffix)
     pub biller_id: String,
     /// Transaction amount
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub tip: Option<TipPolicy>,
     /// Reference 1
     pub ref1: String,
     /// Reference 2
     pub ref2: Option<String>,
-    /// (Undocumented) Reference 3
+    /// (Undocumented) Reference 3, written as Terminal Label (Tag 62.07)
     pub ref3: Option<String>,
+    /// Additional Data Field Template (Tag 62)
+    ///
+    /// `ref3` takes precedence over `terminal_label` when both are set
+    pub additional_data: Option<AdditionalData>,
 }
 
 /// Generate PromptPay Bill Payment (Tag 30) QR Code
@@ -36,13 +46,19 @@ pub fn bill_payment(config: BillPaymentConfig) -> Result<String> {
     ];
 
     if let Some(amount) = config.amount {
-        payload.push(tag("54", &format!("{amount:.2}")));
+        payload.push(tag("54", &amount.rescale(2)?.to_string()));
     }
 
+    if let Some(tip) = &config.tip {
+        payload.extend(tip.to_tags(2)?);
+    }
+
+    let mut additional_data = config.additional_data.unwrap_or_default();
     if let Some(ref3) = config.ref3 {
-        let tag62_data = vec![tag("07", &ref3)];
-        payload.push(tag("62", &encode(&tag62_data)));
+        additional_data.terminal_label = Some(ref3);
     }
+    additional_data.validate()?;
+    payload.extend(additional_data.to_tag());
 
     Ok(with_crc_tag(&encode(&payload), "63", true))
 }
@@ -56,9 +72,11 @@ mod tests {
         let config = BillPaymentConfig {
             biller_id: "0112233445566".to_string(),
             amount: None,
+            tip: None,
             ref1: "CUSTOMER001".to_string(),
             ref2: None,
             ref3: None,
+            additional_data: None,
         };
         let result = bill_payment(config).unwrap();
         assert!(!result.is_empty());
@@ -69,9 +87,11 @@ mod tests {
         let config = BillPaymentConfig {
             biller_id: "0112233445566".to_string(),
             amount: None,
+            tip: None,
             ref1: "CUSTOMER001".to_string(),
             ref2: Some("INV001".to_string()),
             ref3: Some("SCB".to_string()),
+            additional_data: None,
         };
         let result = bill_payment(config).unwrap();
         assert_eq!(result, "00020101021130620016A000000677010112011301122334455660211CUSTOMER0010306INV00153037645802TH62070703SCB6304780E");
@@ -81,12 +101,33 @@ mod tests {
     fn test_bill_payment_with_amount() {
         let config = BillPaymentConfig {
             biller_id: "0112233445566".to_string(),
-            amount: Some(100.50),
+            amount: Some(Amount::try_from(100.50).unwrap()),
+            tip: None,
             ref1: "CUSTOMER001".to_string(),
             ref2: None,
             ref3: None,
+            additional_data: None,
         };
         let result = bill_payment(config).unwrap();
         assert!(result.contains("5406100.50"));
     }
+
+    #[test]
+    fn test_bill_payment_ref3_with_additional_data() {
+        let config = BillPaymentConfig {
+            biller_id: "0112233445566".to_string(),
+            amount: None,
+            tip: None,
+            ref1: "CUSTOMER001".to_string(),
+            ref2: None,
+            ref3: Some("SCB".to_string()),
+            additional_data: Some(AdditionalData {
+                store_label: Some("BRANCH01".to_string()),
+                terminal_label: Some("IGNORED".to_string()),
+                ..Default::default()
+            }),
+        };
+        let result = bill_payment(config).unwrap();
+        assert!(result.contains("62190308BRANCH010703SCB"));
+    }
 }
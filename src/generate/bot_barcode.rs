@@ -1,6 +1,8 @@
+use crate::amount::Amount;
 use crate::bot_barcode::BotBarcode;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BotBarcodeConfig {
     /// Biller ID (Tax ID + Suffix)
     pub biller_id: String,
@@ -9,7 +11,7 @@ pub struct BotBarcodeConfig {
     /// Reference No. 2
     pub ref2: Option<String>,
     /// Transaction amount
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
 }
 
 /// Generate BOT Barcode
@@ -40,7 +42,7 @@ mod tests {
             biller_id: "099400016550100".to_string(),
             ref1: "123456789012".to_string(),
             ref2: Some("670429".to_string()),
-            amount: Some(3649.22),
+            amount: Some(Amount::try_from(3649.22).unwrap()),
         };
         let result = bot_barcode(config);
         assert_eq!(result, "|099400016550100\r123456789012\r670429\r364922");
@@ -52,7 +54,7 @@ mod tests {
             biller_id: "099999999999990".to_string(),
             ref1: "111222333444".to_string(),
             ref2: None,
-            amount: Some(100.0),
+            amount: Some(Amount::try_from(100.0).unwrap()),
         };
         let result = bot_barcode(config);
         assert_eq!(result, "|099999999999990\r111222333444\r\r10000");
@@ -0,0 +1,819 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::bank::Bank;
+use crate::error::PromptParseError;
+use crate::generate::any_id::{any_id, is_local_mobile_number, AnyIdConfig, ProxyType};
+use crate::generate::bill_payment::{bill_payment, BillPaymentConfig};
+use crate::generate::bot_barcode::{bot_barcode, BotBarcodeConfig};
+use crate::generate::slip_verify::{slip_verify, SlipVerifyConfig};
+use crate::generate::tip::TipPolicy;
+use crate::generate::true_money::{true_money, TrueMoneyConfig};
+use crate::generate::true_money_slip_verify::{true_money_slip_verify, TrueMoneySlipVerifyConfig};
+use crate::Result;
+
+/// Typestate marker for a required field that has not been set
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// Typestate marker for a required field that has been set
+#[derive(Debug, Clone)]
+pub struct Set<T>(T);
+
+/// Entry point for the typed generator builders
+///
+/// Required fields are tracked in the builder's type, so `build()` only
+/// exists once all of them are set. Inputs are validated by `build()`.
+///
+/// ```
+/// use promptparse::generate::PromptPay;
+/// use promptparse::Amount;
+///
+/// let payload = PromptPay::any_id()
+///     .mobile("0812223333")
+///     .amount("30.00".parse::<Amount>().unwrap())
+///     .build()
+///     .unwrap();
+/// assert!(payload.starts_with("000201010212"));
+/// ```
+///
+/// Leaving out a required field does not compile:
+///
+/// ```compile_fail
+/// use promptparse::generate::PromptPay;
+///
+/// let payload = PromptPay::bill_payment().biller_id("0112233445566").build();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PromptPay;
+
+impl PromptPay {
+    /// PromptPay AnyID (Tag 29) QR Code
+    pub fn any_id() -> AnyIdBuilder {
+        AnyIdBuilder::new()
+    }
+
+    /// PromptPay Bill Payment (Tag 30) QR Code
+    pub fn bill_payment() -> BillPaymentBuilder {
+        BillPaymentBuilder::new()
+    }
+
+    /// TrueMoney Wallet QR Code
+    pub fn true_money() -> TrueMoneyBuilder {
+        TrueMoneyBuilder::new()
+    }
+
+    /// Slip Verify QR Code
+    pub fn slip_verify() -> SlipVerifyBuilder {
+        SlipVerifyBuilder::new()
+    }
+
+    /// TrueMoney Slip Verify QR Code
+    pub fn true_money_slip_verify() -> TrueMoneySlipVerifyBuilder {
+        TrueMoneySlipVerifyBuilder::new()
+    }
+
+    /// BOT Barcode
+    pub fn bot_barcode() -> BotBarcodeBuilder {
+        BotBarcodeBuilder::new()
+    }
+}
+
+fn require(value: &str, field: &str) -> Result<()> {
+    if value.is_empty() {
+        return Err(PromptParseError::MissingRequiredField(field.to_string()));
+    }
+    Ok(())
+}
+
+fn check(valid: bool, tag_id: &str, rule: &str) -> Result<()> {
+    if !valid {
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: tag_id.to_string(),
+            rule: rule.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Bill Payment reference (Tags 30.02 and 30.03): up to 20 upper case letters or digits
+fn is_bill_reference(value: &str) -> bool {
+    value.len() <= 20
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+}
+
+/// Builder for [`AnyIdConfig`], created by [`PromptPay::any_id`]
+#[derive(Debug, Clone)]
+pub struct AnyIdBuilder<Target = Missing> {
+    target: Target,
+    amount: Option<Amount>,
+    tip: Option<TipPolicy>,
+    additional_data: Option<AdditionalData>,
+}
+
+impl AnyIdBuilder {
+    pub fn new() -> Self {
+        Self {
+            target: Missing,
+            amount: None,
+            tip: None,
+            additional_data: None,
+        }
+    }
+
+    /// Recipient of the given proxy type
+    pub fn target(
+        self,
+        proxy_type: ProxyType,
+        target: &str,
+    ) -> AnyIdBuilder<Set<(ProxyType, String)>> {
+        AnyIdBuilder {
+            target: Set((proxy_type, target.to_string())),
+            amount: self.amount,
+            tip: self.tip,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Recipient mobile number (e.g. `0812223333`)
+    pub fn mobile(self, mobile: &str) -> AnyIdBuilder<Set<(ProxyType, String)>> {
+        self.target(ProxyType::Msisdn, mobile)
+    }
+
+    /// Recipient National ID or Tax ID
+    pub fn national_id(self, id: &str) -> AnyIdBuilder<Set<(ProxyType, String)>> {
+        self.target(ProxyType::NatId, id)
+    }
+
+    /// Recipient E-Wallet ID
+    pub fn ewallet_id(self, id: &str) -> AnyIdBuilder<Set<(ProxyType, String)>> {
+        self.target(ProxyType::EWalletId, id)
+    }
+
+    /// Recipient bank account (reserved)
+    pub fn bank_account(self, account: &str) -> AnyIdBuilder<Set<(ProxyType, String)>> {
+        self.target(ProxyType::BankAcc, account)
+    }
+}
+
+impl Default for AnyIdBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Target> AnyIdBuilder<Target> {
+    /// Transaction amount
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub fn tip(mut self, tip: TipPolicy) -> Self {
+        self.tip = Some(tip);
+        self
+    }
+
+    /// Additional Data Field Template (Tag 62)
+    pub fn additional_data(mut self, additional_data: AdditionalData) -> Self {
+        self.additional_data = Some(additional_data);
+        self
+    }
+}
+
+impl AnyIdBuilder<Set<(ProxyType, String)>> {
+    /// Config for [`any_id`], without validation
+    pub fn config(self) -> AnyIdConfig {
+        let Set((proxy_type, target)) = self.target;
+        AnyIdConfig {
+            proxy_type,
+            target,
+            amount: self.amount,
+            tip: self.tip,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Validate fields and generate the QR Code payload
+    pub fn build(self) -> Result<String> {
+        any_id(self.config())
+    }
+}
+
+/// Builder for [`BillPaymentConfig`], created by [`PromptPay::bill_payment`]
+#[derive(Debug, Clone)]
+pub struct BillPaymentBuilder<BillerId = Missing, Ref1 = Missing> {
+    biller_id: BillerId,
+    ref1: Ref1,
+    amount: Option<Amount>,
+    tip: Option<TipPolicy>,
+    ref2: Option<String>,
+    ref3: Option<String>,
+    additional_data: Option<AdditionalData>,
+}
+
+impl BillPaymentBuilder {
+    pub fn new() -> Self {
+        Self {
+            biller_id: Missing,
+            ref1: Missing,
+            amount: None,
+            tip: None,
+            ref2: None,
+            ref3: None,
+            additional_data: None,
+        }
+    }
+}
+
+impl Default for BillPaymentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ref1> BillPaymentBuilder<Missing, Ref1> {
+    /// Biller ID (National ID or Tax ID + Suffix)
+    pub fn biller_id(self, biller_id: &str) -> BillPaymentBuilder<Set<String>, Ref1> {
+        BillPaymentBuilder {
+            biller_id: Set(biller_id.to_string()),
+            ref1: self.ref1,
+            amount: self.amount,
+            tip: self.tip,
+            ref2: self.ref2,
+            ref3: self.ref3,
+            additional_data: self.additional_data,
+        }
+    }
+}
+
+impl<BillerId> BillPaymentBuilder<BillerId, Missing> {
+    /// Reference 1
+    pub fn ref1(self, ref1: &str) -> BillPaymentBuilder<BillerId, Set<String>> {
+        BillPaymentBuilder {
+            biller_id: self.biller_id,
+            ref1: Set(ref1.to_string()),
+            amount: self.amount,
+            tip: self.tip,
+            ref2: self.ref2,
+            ref3: self.ref3,
+            additional_data: self.additional_data,
+        }
+    }
+}
+
+impl<BillerId, Ref1> BillPaymentBuilder<BillerId, Ref1> {
+    /// Transaction amount
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub fn tip(mut self, tip: TipPolicy) -> Self {
+        self.tip = Some(tip);
+        self
+    }
+
+    /// Reference 2
+    pub fn ref2(mut self, ref2: &str) -> Self {
+        self.ref2 = Some(ref2.to_string());
+        self
+    }
+
+    /// (Undocumented) Reference 3, written as Terminal Label (Tag 62.07)
+    pub fn ref3(mut self, ref3: &str) -> Self {
+        self.ref3 = Some(ref3.to_string());
+        self
+    }
+
+    /// Additional Data Field Template (Tag 62)
+    pub fn additional_data(mut self, additional_data: AdditionalData) -> Self {
+        self.additional_data = Some(additional_data);
+        self
+    }
+}
+
+impl BillPaymentBuilder<Set<String>, Set<String>> {
+    /// Config for [`bill_payment`], without validation
+    pub fn config(self) -> BillPaymentConfig {
+        BillPaymentConfig {
+            biller_id: self.biller_id.0,
+            amount: self.amount,
+            tip: self.tip,
+            ref1: self.ref1.0,
+            ref2: self.ref2,
+            ref3: self.ref3,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Validate fields and generate the QR Code payload
+    pub fn build(self) -> Result<String> {
+        let config = self.config();
+        require(&config.biller_id, "Biller ID")?;
+        require(&config.ref1, "Reference 1")?;
+        check(
+            matches!(config.biller_id.len(), 13 | 15)
+                && config.biller_id.bytes().all(|b| b.is_ascii_digit()),
+            "30.01",
+            "Biller ID must be a 13-digit National ID or 15-digit Tax ID + Suffix",
+        )?;
+        check(
+            is_bill_reference(&config.ref1),
+            "30.02",
+            "Reference 1 must be up to 20 upper case letters or digits",
+        )?;
+        if let Some(ref2) = &config.ref2 {
+            check(
+                !ref2.is_empty() && is_bill_reference(ref2),
+                "30.03",
+                "Reference 2 must be 1-20 upper case letters or digits",
+            )?;
+        }
+        // Reference 3 is written as Terminal Label (Tag 62.07), whose length
+        // is checked by `bill_payment` along with the rest of Tag 62
+        bill_payment(config)
+    }
+}
+
+/// Builder for [`TrueMoneyConfig`], created by [`PromptPay::true_money`]
+#[derive(Debug, Clone)]
+pub struct TrueMoneyBuilder<MobileNo = Missing> {
+    mobile_no: MobileNo,
+    amount: Option<Amount>,
+    message: Option<String>,
+    additional_data: Option<AdditionalData>,
+}
+
+impl TrueMoneyBuilder {
+    pub fn new() -> Self {
+        Self {
+            mobile_no: Missing,
+            amount: None,
+            message: None,
+            additional_data: None,
+        }
+    }
+
+    /// Mobile number of the wallet
+    pub fn mobile(self, mobile_no: &str) -> TrueMoneyBuilder<Set<String>> {
+        TrueMoneyBuilder {
+            mobile_no: Set(mobile_no.to_string()),
+            amount: self.amount,
+            message: self.message,
+            additional_data: self.additional_data,
+        }
+    }
+}
+
+impl Default for TrueMoneyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<MobileNo> TrueMoneyBuilder<MobileNo> {
+    /// Transaction amount
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Personal message (Tag 81)
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// Additional Data Field Template (Tag 62)
+    pub fn additional_data(mut self, additional_data: AdditionalData) -> Self {
+        self.additional_data = Some(additional_data);
+        self
+    }
+}
+
+impl TrueMoneyBuilder<Set<String>> {
+    /// Config for [`true_money`], without validation
+    pub fn config(self) -> TrueMoneyConfig {
+        TrueMoneyConfig {
+            mobile_no: self.mobile_no.0,
+            amount: self.amount,
+            message: self.message,
+            additional_data: self.additional_data,
+        }
+    }
+
+    /// Validate fields and generate the QR Code payload
+    pub fn build(self) -> Result<String> {
+        let config = self.config();
+        if !is_local_mobile_number(&config.mobile_no) {
+            return Err(PromptParseError::InvalidMobileNumber);
+        }
+        true_money(config)
+    }
+}
+
+/// Builder for [`SlipVerifyConfig`], created by [`PromptPay::slip_verify`]
+#[derive(Debug, Clone)]
+pub struct SlipVerifyBuilder<SendingBank = Missing, TransRef = Missing> {
+    sending_bank: SendingBank,
+    trans_ref: TransRef,
+}
+
+impl SlipVerifyBuilder {
+    pub fn new() -> Self {
+        Self {
+            sending_bank: Missing,
+            trans_ref: Missing,
+        }
+    }
+}
+
+impl Default for SlipVerifyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TransRef> SlipVerifyBuilder<Missing, TransRef> {
+    /// Sending bank
+    pub fn bank(self, bank: Bank) -> SlipVerifyBuilder<Set<Bank>, TransRef> {
+        SlipVerifyBuilder {
+            sending_bank: Set(bank),
+            trans_ref: self.trans_ref,
+        }
+    }
+}
+
+impl<SendingBank> SlipVerifyBuilder<SendingBank, Missing> {
+    /// Transaction reference
+    pub fn trans_ref(self, trans_ref: &str) -> SlipVerifyBuilder<SendingBank, Set<String>> {
+        SlipVerifyBuilder {
+            sending_bank: self.sending_bank,
+            trans_ref: Set(trans_ref.to_string()),
+        }
+    }
+}
+
+impl SlipVerifyBuilder<Set<Bank>, Set<String>> {
+    /// Config for [`slip_verify`], without validation
+    pub fn config(self) -> SlipVerifyConfig {
+        SlipVerifyConfig::new(self.sending_bank.0, &self.trans_ref.0)
+    }
+
+    /// Validate fields and generate the QR Code payload
+    pub fn build(self) -> Result<String> {
+        let config = self.config();
+        require(&config.sending_bank, "Sending bank")?;
+        require(&config.trans_ref, "Transaction reference")?;
+        check(
+            config.sending_bank.len() == 3
+                && config.sending_bank.bytes().all(|b| b.is_ascii_digit()),
+            "00.01",
+            "sending bank must be a 3-digit bank code",
+        )?;
+        check(
+            config.trans_ref.len() <= 25
+                && config.trans_ref.bytes().all(|b| b.is_ascii_alphanumeric()),
+            "00.02",
+            "transaction reference must be up to 25 letters or digits",
+        )?;
+        Ok(slip_verify(config))
+    }
+}
+
+/// Builder for [`TrueMoneySlipVerifyConfig`], created by
+/// [`PromptPay::true_money_slip_verify`]
+#[derive(Debug, Clone)]
+pub struct TrueMoneySlipVerifyBuilder<EventType = Missing, TransactionId = Missing, Date = Missing>
+{
+    event_type: EventType,
+    transaction_id: TransactionId,
+    date: Date,
+}
+
+impl TrueMoneySlipVerifyBuilder {
+    pub fn new() -> Self {
+        Self {
+            event_type: Missing,
+            transaction_id: Missing,
+            date: Missing,
+        }
+    }
+}
+
+impl Default for TrueMoneySlipVerifyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TransactionId, Date> TrueMoneySlipVerifyBuilder<Missing, TransactionId, Date> {
+    /// Event Type (Example: P2P)
+    pub fn event_type(
+        self,
+        event_type: &str,
+    ) -> TrueMoneySlipVerifyBuilder<Set<String>, TransactionId, Date> {
+        TrueMoneySlipVerifyBuilder {
+            event_type: Set(event_type.to_string()),
+            transaction_id: self.transaction_id,
+            date: self.date,
+        }
+    }
+}
+
+impl<EventType, Date> TrueMoneySlipVerifyBuilder<EventType, Missing, Date> {
+    /// Transaction ID
+    pub fn transaction_id(
+        self,
+        transaction_id: &str,
+    ) -> TrueMoneySlipVerifyBuilder<EventType, Set<String>, Date> {
+        TrueMoneySlipVerifyBuilder {
+            event_type: self.event_type,
+            transaction_id: Set(transaction_id.to_string()),
+            date: self.date,
+        }
+    }
+}
+
+impl<EventType, TransactionId> TrueMoneySlipVerifyBuilder<EventType, TransactionId, Missing> {
+    /// Date (DDMMYYYY)
+    pub fn date(
+        self,
+        date: &str,
+    ) -> TrueMoneySlipVerifyBuilder<EventType, TransactionId, Set<String>> {
+        TrueMoneySlipVerifyBuilder {
+            event_type: self.event_type,
+            transaction_id: self.transaction_id,
+            date: Set(date.to_string()),
+        }
+    }
+}
+
+impl TrueMoneySlipVerifyBuilder<Set<String>, Set<String>, Set<String>> {
+    /// Config for [`true_money_slip_verify`], without validation
+    pub fn config(self) -> TrueMoneySlipVerifyConfig {
+        TrueMoneySlipVerifyConfig {
+            event_type: self.event_type.0,
+            transaction_id: self.transaction_id.0,
+            date: self.date.0,
+        }
+    }
+
+    /// Validate fields and generate the QR Code payload
+    pub fn build(self) -> Result<String> {
+        let config = self.config();
+        require(&config.event_type, "Event type")?;
+        require(&config.transaction_id, "Transaction ID")?;
+        if config.date.len() != 8 || !config.date.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PromptParseError::InvalidTagValue {
+                tag_id: "00.04".to_string(),
+                rule: "date must be DDMMYYYY".to_string(),
+            });
+        }
+        Ok(true_money_slip_verify(config))
+    }
+}
+
+/// Builder for [`BotBarcodeConfig`], created by [`PromptPay::bot_barcode`]
+#[derive(Debug, Clone)]
+pub struct BotBarcodeBuilder<BillerId = Missing, Ref1 = Missing> {
+    biller_id: BillerId,
+    ref1: Ref1,
+    ref2: Option<String>,
+    amount: Option<Amount>,
+}
+
+impl BotBarcodeBuilder {
+    pub fn new() -> Self {
+        Self {
+            biller_id: Missing,
+            ref1: Missing,
+            ref2: None,
+            amount: None,
+        }
+    }
+}
+
+impl Default for BotBarcodeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ref1> BotBarcodeBuilder<Missing, Ref1> {
+    /// Biller ID (Tax ID + Suffix)
+    pub fn biller_id(self, biller_id: &str) -> BotBarcodeBuilder<Set<String>, Ref1> {
+        BotBarcodeBuilder {
+            biller_id: Set(biller_id.to_string()),
+            ref1: self.ref1,
+            ref2: self.ref2,
+            amount: self.amount,
+        }
+    }
+}
+
+impl<BillerId> BotBarcodeBuilder<BillerId, Missing> {
+    /// Reference No. 1 / Customer No.
+    pub fn ref1(self, ref1: &str) -> BotBarcodeBuilder<BillerId, Set<String>> {
+        BotBarcodeBuilder {
+            biller_id: self.biller_id,
+            ref1: Set(ref1.to_string()),
+            ref2: self.ref2,
+            amount: self.amount,
+        }
+    }
+}
+
+impl<BillerId, Ref1> BotBarcodeBuilder<BillerId, Ref1> {
+    /// Reference No. 2
+    pub fn ref2(mut self, ref2: &str) -> Self {
+        self.ref2 = Some(ref2.to_string());
+        self
+    }
+
+    /// Transaction amount
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+}
+
+impl BotBarcodeBuilder<Set<String>, Set<String>> {
+    /// Config for [`bot_barcode`], without validation
+    pub fn config(self) -> BotBarcodeConfig {
+        BotBarcodeConfig {
+            biller_id: self.biller_id.0,
+            ref1: self.ref1.0,
+            ref2: self.ref2,
+            amount: self.amount,
+        }
+    }
+
+    /// Validate fields and generate the barcode
+    pub fn build(self) -> Result<String> {
+        let config = self.config();
+        require(&config.biller_id, "Biller ID")?;
+        require(&config.ref1, "Reference 1")?;
+        if let Some(amount) = config.amount {
+            amount.rescale(2)?;
+        }
+        Ok(bot_barcode(config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_id_builder() {
+        let result = PromptPay::any_id().mobile("0812223333").build().unwrap();
+        assert_eq!(
+            result,
+            "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF"
+        );
+
+        let result = PromptPay::any_id()
+            .amount(Amount::try_from(30.0).unwrap())
+            .mobile("0812223333")
+            .build()
+            .unwrap();
+        assert_eq!(
+            result,
+            "00020101021229370016A0000006770101110113006681222333353037645802TH540530.0063043CAD"
+        );
+
+        assert!(matches!(
+            PromptPay::any_id().mobile("08-1222-3333").build(),
+            Err(PromptParseError::InvalidMobileNumber)
+        ));
+    }
+
+    #[test]
+    fn test_bill_payment_builder() {
+        let config = BillPaymentConfig {
+            biller_id: "0112233445566".to_string(),
+            amount: Some(Amount::try_from(3649.22).unwrap()),
+            tip: None,
+            ref1: "CUSTOMER001".to_string(),
+            ref2: Some("INV001".to_string()),
+            ref3: None,
+            additional_data: None,
+        };
+        let result = PromptPay::bill_payment()
+            .ref1("CUSTOMER001")
+            .biller_id("0112233445566")
+            .ref2("INV001")
+            .amount(Amount::try_from(3649.22).unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(result, bill_payment(config).unwrap());
+
+        assert!(matches!(
+            PromptPay::bill_payment().biller_id("").ref1("REF").build(),
+            Err(PromptParseError::MissingRequiredField(_))
+        ));
+    }
+
+    #[test]
+    fn test_bill_payment_builder_invalid() {
+        let invalid_tag = |result: Result<String>| match result {
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) => tag_id,
+            other => panic!("expected InvalidTagValue, got {other:?}"),
+        };
+        let builder = PromptPay::bill_payment().biller_id("099999999999990");
+
+        assert_eq!(
+            invalid_tag(
+                PromptPay::bill_payment()
+                    .biller_id("09999")
+                    .ref1("REF")
+                    .build()
+            ),
+            "30.01"
+        );
+        assert_eq!(
+            invalid_tag(
+                PromptPay::bill_payment()
+                    .biller_id("0999-999999999")
+                    .ref1("REF")
+                    .build()
+            ),
+            "30.01"
+        );
+        assert_eq!(
+            invalid_tag(builder.clone().ref1("customer001").build()),
+            "30.02"
+        );
+        assert_eq!(
+            invalid_tag(builder.clone().ref1("A".repeat(21).as_str()).build()),
+            "30.02"
+        );
+        assert_eq!(
+            invalid_tag(builder.clone().ref1("REF").ref2("").build()),
+            "30.03"
+        );
+        assert_eq!(
+            invalid_tag(
+                builder
+                    .clone()
+                    .ref1("REF")
+                    .ref3("A".repeat(26).as_str())
+                    .build()
+            ),
+            "62.07"
+        );
+        assert!(builder
+            .ref1("A".repeat(20).as_str())
+            .ref2("INV001")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_slip_builders() {
+        let result = PromptPay::slip_verify()
+            .bank(Bank::Bbl)
+            .trans_ref("0002123123121200011")
+            .build()
+            .unwrap();
+        assert_eq!(
+            result,
+            "004000060000010103002021900021231231212000115102TH91049C30"
+        );
+        assert!(matches!(
+            PromptPay::slip_verify()
+                .bank(Bank::Other("0014".to_string()))
+                .trans_ref("0002123123121200011")
+                .build(),
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+        assert!(matches!(
+            PromptPay::slip_verify()
+                .bank(Bank::Bbl)
+                .trans_ref("0002-1231-2312")
+                .build(),
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+
+        let builder = PromptPay::true_money_slip_verify()
+            .event_type("P2P")
+            .transaction_id("TXN001");
+        assert!(builder.clone().date("01012024").build().is_ok());
+        assert!(matches!(
+            builder.date("2024-01-01").build(),
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+
+        let barcode = PromptPay::bot_barcode()
+            .biller_id("099999999999990")
+            .ref1("111222333444")
+            .build()
+            .unwrap();
+        assert_eq!(barcode, "|099999999999990\r111222333444\r\r0");
+    }
+}
@@ -0,0 +1,130 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::error::PromptParseError;
+use crate::generate::merchant::MerchantQrBuilder;
+use crate::tlv::tag;
+use crate::Result;
+
+/// Application ID of DuitNow QR (Tag 26)
+pub const DUITNOW_AID: &str = "A0000006150001";
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DuitNowConfig {
+    /// PayNet participant code of the acquiring bank or wallet (Tag 26.01)
+    pub acquirer_id: String,
+    /// Merchant ID or account assigned by the acquirer (Tag 26.02)
+    pub merchant_id: String,
+    /// Merchant Category Code (Tag 52, ISO 18245)
+    pub merchant_category_code: String,
+    /// Merchant name (Tag 59, up to 25 characters)
+    pub merchant_name: String,
+    /// Merchant city (Tag 60, up to 15 characters)
+    pub merchant_city: String,
+    /// Postal code (Tag 61)
+    pub postal_code: Option<String>,
+    /// Transaction amount in MYR
+    pub amount: Option<Amount>,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+/// Generate Malaysia DuitNow QR Code
+///
+/// Merchant name and city are mandatory and checked against the EMVCo length limits.
+pub fn duitnow(config: DuitNowConfig) -> Result<String> {
+    for (value, name) in [
+        (&config.acquirer_id, "Acquirer ID (Tag 26.01)"),
+        (&config.merchant_id, "Merchant ID (Tag 26.02)"),
+        (&config.merchant_name, "Merchant Name (Tag 59)"),
+        (&config.merchant_city, "Merchant City (Tag 60)"),
+    ] {
+        if value.is_empty() {
+            return Err(PromptParseError::MissingRequiredField(name.to_string()));
+        }
+    }
+
+    let tag26_data = vec![
+        tag("00", DUITNOW_AID),
+        tag("01", &config.acquirer_id),
+        tag("02", &config.merchant_id),
+    ];
+
+    let mut builder = MerchantQrBuilder::new()
+        .merchant_account("26", tag26_data)
+        .merchant_category_code(&config.merchant_category_code)
+        .currency("458")
+        .country("MY")
+        .merchant_name(&config.merchant_name)
+        .merchant_city(&config.merchant_city);
+
+    if let Some(postal_code) = &config.postal_code {
+        builder = builder.postal_code(postal_code);
+    }
+
+    if let Some(amount) = config.amount {
+        builder = builder.amount(amount);
+    }
+
+    if let Some(additional_data) = config.additional_data {
+        builder = builder.additional_data(additional_data);
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> DuitNowConfig {
+        DuitNowConfig {
+            acquirer_id: "890053".to_string(),
+            merchant_id: "0000000000012345".to_string(),
+            merchant_category_code: "5812".to_string(),
+            merchant_name: "KEDAI KOPI".to_string(),
+            merchant_city: "KUALA LUMPUR".to_string(),
+            postal_code: None,
+            amount: None,
+            additional_data: None,
+        }
+    }
+
+    #[test]
+    fn test_duitnow() {
+        let result = duitnow(config()).unwrap();
+        assert!(result.starts_with(
+            "00020101021126480014A00000061500010106890053021600000000000123455204581253034585802MY"
+        ));
+        assert!(result.contains("5910KEDAI KOPI6012KUALA LUMPUR6304"));
+
+        let result = duitnow(DuitNowConfig {
+            amount: Some("12.30".parse().unwrap()),
+            ..config()
+        })
+        .unwrap();
+        assert!(result.starts_with("000201010212"));
+        assert!(result.contains("540512.30"));
+    }
+
+    #[test]
+    fn test_duitnow_merchant_required() {
+        let result = duitnow(DuitNowConfig {
+            merchant_city: String::new(),
+            ..config()
+        });
+        assert!(matches!(
+            result,
+            Err(PromptParseError::MissingRequiredField(_))
+        ));
+
+        let result = duitnow(DuitNowConfig {
+            merchant_city: "KUALA LUMPUR CITY CENTRE".to_string(),
+            ..config()
+        });
+        assert!(matches!(
+            result,
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+    }
+}
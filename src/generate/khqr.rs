@@ -0,0 +1,270 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::error::PromptParseError;
+use crate::generate::merchant::MerchantQrBuilder;
+use crate::tlv::{tag, TlvTag};
+use crate::Result;
+
+/// KHQR currency (Tag 53)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum KhqrCurrency {
+    /// Cambodian riel (`116`), whole amounts only
+    Khr,
+    /// US dollar (`840`)
+    Usd,
+}
+
+impl KhqrCurrency {
+    /// ISO 4217 numeric code
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            KhqrCurrency::Khr => "116",
+            KhqrCurrency::Usd => "840",
+        }
+    }
+
+    /// Get currency from its ISO 4217 numeric code
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "116" => Some(KhqrCurrency::Khr),
+            "840" => Some(KhqrCurrency::Usd),
+            _ => None,
+        }
+    }
+
+    /// Decimal places of amounts in this currency
+    pub fn decimals(&self) -> u8 {
+        match self {
+            KhqrCurrency::Khr => 0,
+            KhqrCurrency::Usd => 2,
+        }
+    }
+}
+
+/// Bakong account of an individual (Tag 29) or a merchant (Tag 30)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum KhqrAccount {
+    /// Individual (Tag 29)
+    Individual {
+        /// Account number or other account information (Tag 29.01)
+        account_information: Option<String>,
+        /// Acquiring bank (Tag 29.02)
+        acquiring_bank: Option<String>,
+    },
+    /// Merchant (Tag 30)
+    Merchant {
+        /// Merchant ID (Tag 30.01)
+        merchant_id: String,
+        /// Acquiring bank (Tag 30.02)
+        acquiring_bank: String,
+    },
+}
+
+impl KhqrAccount {
+    /// Tag ID of the account template
+    pub fn tag_id(&self) -> &'static str {
+        match self {
+            KhqrAccount::Individual { .. } => "29",
+            KhqrAccount::Merchant { .. } => "30",
+        }
+    }
+
+    fn to_tags(&self, bakong_account_id: &str) -> Vec<TlvTag> {
+        let mut tags = vec![tag("00", bakong_account_id)];
+        match self {
+            KhqrAccount::Individual {
+                account_information,
+                acquiring_bank,
+            } => {
+                if let Some(account_information) = account_information {
+                    tags.push(tag("01", account_information));
+                }
+                if let Some(acquiring_bank) = acquiring_bank {
+                    tags.push(tag("02", acquiring_bank));
+                }
+            }
+            KhqrAccount::Merchant {
+                merchant_id,
+                acquiring_bank,
+            } => {
+                tags.push(tag("01", merchant_id));
+                tags.push(tag("02", acquiring_bank));
+            }
+        }
+        tags
+    }
+}
+
+/// Creation and expiry time of a KHQR (Tag 99), in milliseconds since the Unix epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KhqrTimestamp {
+    /// Creation time (Tag 99.00)
+    pub created_at: u64,
+    /// Expiry time (Tag 99.01)
+    pub expires_at: Option<u64>,
+}
+
+impl KhqrTimestamp {
+    /// Whether the QR Code can no longer be paid at `now` (milliseconds since the Unix epoch)
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    fn to_tags(self) -> Vec<TlvTag> {
+        let mut tags = vec![tag("00", &self.created_at.to_string())];
+        if let Some(expires_at) = self.expires_at {
+            tags.push(tag("01", &expires_at.to_string()));
+        }
+        tags
+    }
+}
+
+/// Configuration of an individual or merchant KHQR
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KhqrConfig {
+    /// Bakong account ID (e.g. `john_smith@devb`)
+    pub bakong_account_id: String,
+    /// Individual or merchant account details
+    pub account: KhqrAccount,
+    /// Merchant Category Code (Tag 52), `5999` for individuals
+    pub merchant_category_code: String,
+    /// Merchant name (Tag 59)
+    pub merchant_name: String,
+    /// Merchant city (Tag 60)
+    pub merchant_city: String,
+    /// Transaction currency
+    pub currency: KhqrCurrency,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+    /// Creation and expiry time (Tag 99)
+    pub timestamp: Option<KhqrTimestamp>,
+}
+
+/// Generate Cambodia KHQR (Bakong) QR Code
+pub fn khqr(config: KhqrConfig) -> Result<String> {
+    let account_tag = config.account.tag_id();
+    let valid_id =
+        config.bakong_account_id.contains('@') && config.bakong_account_id.chars().count() <= 32;
+    if !valid_id {
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: format!("{account_tag}.00"),
+            rule: "Bakong account ID must be up to 32 characters in the form name@bank".to_string(),
+        });
+    }
+
+    let mut builder = MerchantQrBuilder::new()
+        .merchant_account(
+            account_tag,
+            config.account.to_tags(&config.bakong_account_id),
+        )
+        .merchant_category_code(&config.merchant_category_code)
+        .currency(config.currency.to_code())
+        .country("KH")
+        .merchant_name(&config.merchant_name)
+        .merchant_city(&config.merchant_city);
+
+    if let Some(amount) = config.amount {
+        builder = builder.amount(amount.rescale(config.currency.decimals())?);
+    }
+
+    if let Some(additional_data) = config.additional_data {
+        builder = builder.additional_data(additional_data);
+    }
+
+    if let Some(timestamp) = config.timestamp {
+        if timestamp
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= timestamp.created_at)
+        {
+            return Err(PromptParseError::InvalidTagValue {
+                tag_id: "99.01".to_string(),
+                rule: "expiry must be after creation".to_string(),
+            });
+        }
+        builder = builder.template("99", timestamp.to_tags());
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> KhqrConfig {
+        KhqrConfig {
+            bakong_account_id: "john_smith@devb".to_string(),
+            account: KhqrAccount::Individual {
+                account_information: None,
+                acquiring_bank: None,
+            },
+            merchant_category_code: "5999".to_string(),
+            merchant_name: "John Smith".to_string(),
+            merchant_city: "PHNOM PENH".to_string(),
+            currency: KhqrCurrency::Khr,
+            amount: None,
+            additional_data: None,
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_khqr_individual() {
+        let result = khqr(config()).unwrap();
+        assert!(result.starts_with(
+            "00020101021129190015john_smith@devb520459995303116\
+             5802KH5910John Smith6010PHNOM PENH6304"
+        ));
+    }
+
+    #[test]
+    fn test_khqr_merchant_with_timestamp() {
+        let result = khqr(KhqrConfig {
+            account: KhqrAccount::Merchant {
+                merchant_id: "123456".to_string(),
+                acquiring_bank: "Dev Bank".to_string(),
+            },
+            currency: KhqrCurrency::Usd,
+            amount: Some("1.5".parse().unwrap()),
+            timestamp: Some(KhqrTimestamp {
+                created_at: 1_700_000_000_000,
+                expires_at: Some(1_700_000_900_000),
+            }),
+            ..config()
+        })
+        .unwrap();
+        assert!(result.starts_with("00020101021230410015john_smith@devb01061234560208Dev Bank"));
+        assert!(result.contains("53038405404"));
+        assert!(result.contains("1.50"));
+        assert!(result.contains("99340013170000000000001131700000900000"));
+    }
+
+    #[test]
+    fn test_khqr_invalid() {
+        let result = khqr(KhqrConfig {
+            bakong_account_id: "john_smith".to_string(),
+            ..config()
+        });
+        assert!(matches!(
+            result,
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+
+        let result = khqr(KhqrConfig {
+            amount: Some("100.50".parse().unwrap()),
+            ..config()
+        });
+        assert!(matches!(
+            result,
+            Err(PromptParseError::AmountPrecisionExceeded { max_decimals: 0 })
+        ));
+    }
+}
@@ -0,0 +1,401 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::error::PromptParseError;
+use crate::generate::tip::TipPolicy;
+use crate::spec::{self, check_value, is_merchant_account, parse_id};
+use crate::tlv::{encode, tag, with_crc_tag, TlvTag};
+use crate::utils::currency::currency_decimals;
+use crate::Result;
+use std::collections::BTreeMap;
+
+/// Builder for any EMVCo Merchant-Presented Mode (MPM) QR Code
+///
+/// Tags are emitted in ascending ID order followed by the CRC (Tag 63).
+/// [`MerchantQrBuilder::build`] enforces the EMVCo length limits and
+/// mandatory fields: at least one Merchant Account Information (02-51),
+/// Merchant Category Code (52), Transaction Currency (53), Country Code (58),
+/// Merchant Name (59) and Merchant City (60).
+///
+/// ```
+/// use promptparse::generate::MerchantQrBuilder;
+/// use promptparse::tag;
+///
+/// let payload = MerchantQrBuilder::new()
+///     .merchant_account("29", vec![tag("00", "A000000677010111"), tag("01", "0066812223333")])
+///     .merchant_category_code("5812")
+///     .currency("764")
+///     .country("TH")
+///     .merchant_name("CAFE AMAZON")
+///     .merchant_city("BANGKOK")
+///     .build()
+///     .unwrap();
+/// assert!(payload.starts_with("000201010211"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MerchantQrBuilder {
+    dynamic: Option<bool>,
+    merchant_accounts: BTreeMap<String, TlvTag>,
+    merchant_category_code: Option<String>,
+    currency: Option<String>,
+    amount: Option<Amount>,
+    tip: Option<TipPolicy>,
+    country: Option<String>,
+    merchant_name: Option<String>,
+    merchant_city: Option<String>,
+    postal_code: Option<String>,
+    additional_data: AdditionalData,
+    language: Vec<TlvTag>,
+    templates: BTreeMap<String, TlvTag>,
+}
+
+impl MerchantQrBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set Point of Initiation Method (Tag 01) to dynamic (`12`) or static (`11`)
+    ///
+    /// Defaults to dynamic when an amount is set, static otherwise
+    pub fn dynamic(mut self, dynamic: bool) -> Self {
+        self.dynamic = Some(dynamic);
+        self
+    }
+
+    /// Add Merchant Account Information template (Tags 02-51)
+    ///
+    /// Setting the same tag ID twice replaces the previous value
+    pub fn merchant_account(mut self, tag_id: &str, sub_tags: Vec<TlvTag>) -> Self {
+        let value = encode(&sub_tags);
+        self.merchant_accounts.insert(
+            tag_id.to_string(),
+            TlvTag::with_sub_tags(tag_id.to_string(), value, sub_tags),
+        );
+        self
+    }
+
+    /// Add primitive Merchant Account Information (Tags 02-25, e.g. card network IDs)
+    pub fn merchant_account_value(mut self, tag_id: &str, value: &str) -> Self {
+        self.merchant_accounts
+            .insert(tag_id.to_string(), tag(tag_id, value));
+        self
+    }
+
+    /// Set Merchant Category Code (Tag 52, ISO 18245)
+    pub fn merchant_category_code(mut self, mcc: &str) -> Self {
+        self.merchant_category_code = Some(mcc.to_string());
+        self
+    }
+
+    /// Set Transaction Currency (Tag 53, ISO 4217 numeric)
+    pub fn currency(mut self, currency: &str) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    /// Set Transaction Amount (Tag 54)
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set Tip or Convenience Indicator (Tag 55) and the matching fee (Tag 56 or 57)
+    ///
+    /// A fixed fee is checked against the decimal places of the transaction currency
+    pub fn tip(mut self, tip: TipPolicy) -> Self {
+        self.tip = Some(tip);
+        self
+    }
+
+    /// Set Country Code (Tag 58, ISO 3166-1 alpha-2)
+    pub fn country(mut self, country: &str) -> Self {
+        self.country = Some(country.to_string());
+        self
+    }
+
+    /// Set Merchant Name (Tag 59)
+    pub fn merchant_name(mut self, name: &str) -> Self {
+        self.merchant_name = Some(name.to_string());
+        self
+    }
+
+    /// Set Merchant City (Tag 60)
+    pub fn merchant_city(mut self, city: &str) -> Self {
+        self.merchant_city = Some(city.to_string());
+        self
+    }
+
+    /// Set Postal Code (Tag 61)
+    pub fn postal_code(mut self, postal_code: &str) -> Self {
+        self.postal_code = Some(postal_code.to_string());
+        self
+    }
+
+    /// Set Additional Data Field Template (Tag 62)
+    pub fn additional_data(mut self, additional_data: AdditionalData) -> Self {
+        self.additional_data = additional_data;
+        self
+    }
+
+    /// Set a data object of Additional Data Field Template (Tag 62)
+    pub fn additional_data_field(mut self, sub_tag_id: &str, value: &str) -> Self {
+        let mut tags = self.additional_data.to_tags();
+        tags.retain(|t| t.id != sub_tag_id);
+        tags.push(tag(sub_tag_id, value));
+        self.additional_data = AdditionalData::from_tags(&tags);
+        self
+    }
+
+    /// Set Merchant Information - Language Template (Tag 64)
+    ///
+    /// # Arguments
+    /// * `language` - Language Preference (ISO 639 alpha-2, e.g. `TH`)
+    /// * `name` - Merchant Name in the alternate language
+    /// * `city` - Merchant City in the alternate language
+    pub fn merchant_information_language(
+        mut self,
+        language: &str,
+        name: &str,
+        city: Option<&str>,
+    ) -> Self {
+        self.language = vec![tag("00", language), tag("01", name)];
+        if let Some(city) = city {
+            self.language.push(tag("02", city));
+        }
+        self
+    }
+
+    /// Add RFU for EMVCo (Tags 65-79) or Unreserved Template (Tags 80-99)
+    pub fn template(mut self, tag_id: &str, sub_tags: Vec<TlvTag>) -> Self {
+        let value = encode(&sub_tags);
+        self.templates.insert(
+            tag_id.to_string(),
+            TlvTag::with_sub_tags(tag_id.to_string(), value, sub_tags),
+        );
+        self
+    }
+
+    /// Validate fields and generate the QR Code payload
+    pub fn build(self) -> Result<String> {
+        if self.merchant_accounts.is_empty() {
+            return Err(PromptParseError::MissingRequiredField(
+                "Merchant Account Information (Tags 02-51)".to_string(),
+            ));
+        }
+
+        for id in self.merchant_accounts.keys() {
+            if !is_merchant_account(id) {
+                return Err(invalid(
+                    id,
+                    "Merchant Account Information must use tags 02-51",
+                ));
+            }
+        }
+
+        for id in self.templates.keys() {
+            if !matches!(parse_id(id), Some(65..=99)) {
+                return Err(invalid(id, "templates must use tags 65-99"));
+            }
+        }
+
+        let mcc = required(self.merchant_category_code, spec::MERCHANT_CATEGORY_CODE)?;
+        let currency = required(self.currency, spec::TRANSACTION_CURRENCY)?;
+        let country = required(self.country, spec::COUNTRY_CODE)?;
+        let merchant_name = required(self.merchant_name, spec::MERCHANT_NAME)?;
+        let merchant_city = required(self.merchant_city, spec::MERCHANT_CITY)?;
+
+        let dynamic = self.dynamic.unwrap_or(self.amount.is_some());
+        let mut payload = vec![
+            tag(spec::PAYLOAD_FORMAT_INDICATOR, "01"),
+            tag(spec::POINT_OF_INITIATION, if dynamic { "12" } else { "11" }),
+        ];
+        payload.extend(self.merchant_accounts.into_values());
+        payload.push(tag(spec::MERCHANT_CATEGORY_CODE, &mcc));
+        payload.push(tag(spec::TRANSACTION_CURRENCY, &currency));
+
+        let decimals = currency_decimals(&currency);
+        if let Some(amount) = self.amount {
+            if amount.decimals() > decimals {
+                return Err(PromptParseError::AmountPrecisionExceeded {
+                    max_decimals: decimals,
+                });
+            }
+            payload.push(tag(spec::TRANSACTION_AMOUNT, &amount.to_string()));
+        }
+
+        if let Some(tip) = self.tip {
+            payload.extend(tip.to_tags(decimals)?);
+        }
+
+        payload.push(tag(spec::COUNTRY_CODE, &country));
+        payload.push(tag(spec::MERCHANT_NAME, &merchant_name));
+        payload.push(tag(spec::MERCHANT_CITY, &merchant_city));
+
+        if let Some(postal_code) = self.postal_code {
+            payload.push(tag(spec::POSTAL_CODE, &postal_code));
+        }
+
+        self.additional_data.validate()?;
+        payload.extend(self.additional_data.to_tag());
+
+        if !self.language.is_empty() {
+            payload.push(TlvTag::with_sub_tags(
+                spec::MERCHANT_INFORMATION_LANGUAGE.to_string(),
+                encode(&self.language),
+                self.language,
+            ));
+        }
+
+        payload.extend(self.templates.into_values());
+
+        for tag in &payload {
+            if let Some(rule) = check_value(&tag.id, &tag.value) {
+                return Err(invalid(&tag.id, &rule));
+            }
+            for sub_tag in tag.sub_tags.iter().flatten() {
+                if sub_tag.value.is_empty() || sub_tag.value.chars().count() > 99 {
+                    return Err(invalid(
+                        &format!("{}.{}", tag.id, sub_tag.id),
+                        "value must be 1-99 characters",
+                    ));
+                }
+            }
+        }
+
+        Ok(with_crc_tag(&encode(&payload), spec::CRC, true))
+    }
+}
+
+fn required(value: Option<String>, tag_id: &str) -> Result<String> {
+    value.ok_or_else(|| {
+        let name = spec::data_object(tag_id).map_or("", |o| o.name);
+        PromptParseError::MissingRequiredField(format!("{name} (Tag {tag_id})"))
+    })
+}
+
+fn invalid(tag_id: &str, rule: &str) -> PromptParseError {
+    PromptParseError::InvalidTagValue {
+        tag_id: tag_id.to_string(),
+        rule: rule.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    fn builder() -> MerchantQrBuilder {
+        MerchantQrBuilder::new()
+            .merchant_account(
+                "29",
+                vec![tag("00", "A000000677010111"), tag("01", "0066812223333")],
+            )
+            .merchant_category_code("5812")
+            .currency("764")
+            .country("TH")
+            .merchant_name("CAFE AMAZON")
+            .merchant_city("BANGKOK")
+    }
+
+    #[test]
+    fn test_merchant_qr_static() {
+        let result = builder().build().unwrap();
+        assert_eq!(result, "00020101021129370016A000000677010111011300668122233335204581253037645802TH5911CAFE AMAZON6007BANGKOK63042B48");
+
+        let qr = parse(&result, true, true).unwrap();
+        assert!(qr.validate("63"));
+    }
+
+    #[test]
+    fn test_merchant_qr_all_fields() {
+        let result = builder()
+            .merchant_account_value("04", "4111111111111111")
+            .amount(Amount::from_satang(12050).unwrap())
+            .tip(TipPolicy::Percentage("3.5".parse().unwrap()))
+            .postal_code("10110")
+            .additional_data_field("01", "INV001")
+            .merchant_information_language("TH", "คาเฟ่ อเมซอน", Some("กรุงเทพ"))
+            .template("80", vec![tag("00", "TEST")])
+            .build()
+            .unwrap();
+
+        let qr = parse(&result, true, true).unwrap();
+        let ids: Vec<&str> = qr.get_tags().iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "00", "01", "04", "29", "52", "53", "54", "55", "57", "58", "59", "60", "61", "62",
+                "64", "80", "63"
+            ]
+        );
+        assert_eq!(qr.get_tag_value("01", None), Some("12"));
+        assert_eq!(qr.get_tag_value("54", None), Some("120.50"));
+        assert_eq!(qr.get_tag_value("62", Some("01")), Some("INV001"));
+    }
+
+    #[test]
+    fn test_merchant_qr_tip() {
+        let tip = TipPolicy::Fixed(Amount::from_satang(500).unwrap());
+        let result = builder().tip(tip).build().unwrap();
+        assert!(result.contains("55020256045.00"));
+
+        // Fixed fee is written with the currency's decimal places
+        let result = builder()
+            .tip(TipPolicy::Fixed("5".parse().unwrap()))
+            .build()
+            .unwrap();
+        assert!(result.contains("55020256045.00"));
+
+        let qr = parse(&result, true, true).unwrap();
+        assert_eq!(TipPolicy::from_qr(&qr).unwrap(), Some(tip));
+    }
+
+    #[test]
+    fn test_merchant_qr_missing_fields() {
+        assert!(matches!(
+            MerchantQrBuilder::new().build(),
+            Err(PromptParseError::MissingRequiredField(_))
+        ));
+        assert!(matches!(
+            MerchantQrBuilder::new()
+                .merchant_account("29", vec![tag("00", "A000000677010111")])
+                .build(),
+            Err(PromptParseError::MissingRequiredField(field)) if field.contains("52")
+        ));
+    }
+
+    #[test]
+    fn test_merchant_qr_invalid_fields() {
+        assert!(matches!(
+            builder().merchant_name("A MERCHANT NAME LONGER THAN 25").build(),
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) if tag_id == "59"
+        ));
+        assert!(matches!(
+            builder().merchant_category_code("58A2").build(),
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) if tag_id == "52"
+        ));
+        assert!(matches!(
+            builder().merchant_account("52", vec![tag("00", "X")]).build(),
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) if tag_id == "52"
+        ));
+        assert!(matches!(
+            builder()
+                .tip(TipPolicy::Fixed("1.005".parse().unwrap()))
+                .build(),
+            Err(PromptParseError::AmountPrecisionExceeded { max_decimals: 2 })
+        ));
+        assert!(matches!(
+            builder()
+                .tip(TipPolicy::Percentage("100".parse().unwrap()))
+                .build(),
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) if tag_id == "57"
+        ));
+        assert!(matches!(
+            builder()
+                .currency("704")
+                .amount(Amount::from_satang(150).unwrap())
+                .build(),
+            Err(PromptParseError::AmountPrecisionExceeded { max_decimals: 0 })
+        ));
+    }
+}
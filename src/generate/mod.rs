@@ -1,13 +1,32 @@
 pub mod any_id;
 pub mod bill_payment;
 pub mod bot_barcode;
+pub mod builder;
+pub mod duitnow;
+pub mod khqr;
+pub mod merchant;
+pub mod paynow;
+pub mod qris;
 pub mod slip_verify;
+pub mod tip;
 pub mod true_money;
 pub mod true_money_slip_verify;
+pub mod vietqr;
 
-pub use any_id::{any_id, AnyIdConfig, ProxyType};
+pub use any_id::{any_id, any_id_unchecked, is_national_id, AnyIdConfig, ProxyType};
 pub use bill_payment::{bill_payment, BillPaymentConfig};
 pub use bot_barcode::{bot_barcode, BotBarcodeConfig};
+pub use builder::{
+    AnyIdBuilder, BillPaymentBuilder, BotBarcodeBuilder, PromptPay, SlipVerifyBuilder,
+    TrueMoneyBuilder, TrueMoneySlipVerifyBuilder,
+};
+pub use duitnow::{duitnow, DuitNowConfig};
+pub use khqr::{khqr, KhqrAccount, KhqrConfig, KhqrCurrency, KhqrTimestamp};
+pub use merchant::MerchantQrBuilder;
+pub use paynow::{paynow, PayNowConfig, PayNowProxyType};
+pub use qris::{qris, MerchantCriteria, QrisAccount, QrisConfig};
 pub use slip_verify::{slip_verify, SlipVerifyConfig};
+pub use tip::TipPolicy;
 pub use true_money::{true_money, TrueMoneyConfig};
 pub use true_money_slip_verify::{true_money_slip_verify, TrueMoneySlipVerifyConfig};
+pub use vietqr::{vietqr, VietQrConfig, VietQrService};
@@ -0,0 +1,190 @@
+use crate::amount::Amount;
+use crate::error::PromptParseError;
+use crate::tlv::{encode, tag, with_crc_tag};
+use crate::utils::date::is_valid_yyyymmdd;
+use crate::Result;
+
+/// Globally unique identifier of the PayNow template (Tag 26)
+pub const PAYNOW_GUID: &str = "SG.PAYNOW";
+
+/// PayNow proxy type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PayNowProxyType {
+    /// Singapore mobile number
+    Mobile,
+    /// Unique Entity Number of a business
+    Uen,
+}
+
+impl PayNowProxyType {
+    /// Value of sub-tag 26.01
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            PayNowProxyType::Mobile => "0",
+            PayNowProxyType::Uen => "2",
+        }
+    }
+
+    /// Get proxy type from sub-tag 26.01
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "0" => Some(PayNowProxyType::Mobile),
+            "2" => Some(PayNowProxyType::Uen),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayNowConfig {
+    /// Proxy type
+    pub proxy_type: PayNowProxyType,
+    /// Mobile number (`+6591234567` or `91234567`) or UEN
+    pub proxy_value: String,
+    /// Transaction amount in SGD
+    pub amount: Option<Amount>,
+    /// Allow the payer to change the amount, always true without an amount
+    pub amount_editable: bool,
+    /// Last day the QR Code can be paid (`YYYYMMDD`)
+    pub expiry: Option<String>,
+    /// Merchant name (Tag 59), defaults to `NA`
+    pub merchant_name: Option<String>,
+    /// Bill reference, written as Bill Number (Tag 62.01)
+    pub reference: Option<String>,
+}
+
+/// Generate Singapore PayNow (SGQR) QR Code
+pub fn paynow(config: PayNowConfig) -> Result<String> {
+    let proxy_value = paynow_proxy_value(config.proxy_type, &config.proxy_value)?;
+    let editable = config.amount_editable || config.amount.is_none();
+
+    let mut tag26_data = vec![
+        tag("00", PAYNOW_GUID),
+        tag("01", config.proxy_type.to_code()),
+        tag("02", &proxy_value),
+        tag("03", if editable { "1" } else { "0" }),
+    ];
+
+    if let Some(expiry) = &config.expiry {
+        if !is_valid_yyyymmdd(expiry) {
+            return Err(PromptParseError::InvalidTagValue {
+                tag_id: "26.04".to_string(),
+                rule: "expiry date must be YYYYMMDD".to_string(),
+            });
+        }
+        tag26_data.push(tag("04", expiry));
+    }
+
+    let mut payload = vec![
+        tag("00", "01"),
+        tag("01", if config.amount.is_none() { "11" } else { "12" }),
+        tag("26", &encode(&tag26_data)),
+        tag("52", "0000"),
+        tag("53", "702"),
+    ];
+
+    if let Some(amount) = config.amount {
+        payload.push(tag("54", &amount.rescale(2)?.to_string()));
+    }
+
+    payload.extend([
+        tag("58", "SG"),
+        tag("59", config.merchant_name.as_deref().unwrap_or("NA")),
+        tag("60", "Singapore"),
+    ]);
+
+    if let Some(reference) = &config.reference {
+        payload.push(tag("62", &encode(&[tag("01", reference)])));
+    }
+
+    Ok(with_crc_tag(&encode(&payload), "63", true))
+}
+
+/// Check the proxy value and add the `+65` country code to local mobile numbers
+fn paynow_proxy_value(proxy_type: PayNowProxyType, value: &str) -> Result<String> {
+    match proxy_type {
+        PayNowProxyType::Mobile => {
+            let local = value.strip_prefix("+65").unwrap_or(value);
+            let valid = local.len() == 8
+                && local.bytes().all(|b| b.is_ascii_digit())
+                && matches!(local.as_bytes()[0], b'8' | b'9');
+            if !valid {
+                return Err(PromptParseError::InvalidMobileNumber);
+            }
+            Ok(format!("+65{local}"))
+        }
+        PayNowProxyType::Uen => {
+            let valid = (9..=10).contains(&value.len())
+                && value
+                    .bytes()
+                    .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase());
+            if !valid {
+                return Err(PromptParseError::InvalidTagValue {
+                    tag_id: "26.02".to_string(),
+                    rule: "UEN must be 9-10 uppercase letters or digits".to_string(),
+                });
+            }
+            Ok(value.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paynow_uen() {
+        let config = PayNowConfig {
+            proxy_type: PayNowProxyType::Uen,
+            proxy_value: "201403121W".to_string(),
+            amount: Some("10.50".parse().unwrap()),
+            amount_editable: false,
+            expiry: Some("20991231".to_string()),
+            merchant_name: Some("ACME PTE LTD".to_string()),
+            reference: Some("INV001".to_string()),
+        };
+        let result = paynow(config).unwrap();
+        assert!(result.starts_with("000201010212"));
+        assert!(result.contains("26490009SG.PAYNOW010120210201403121W0301004082099123152040000"));
+        assert!(result.contains("5303702540510.505802SG5912ACME PTE LTD6009Singapore"));
+        assert!(result.contains("62100106INV001"));
+        assert!(crate::parser::parse(&result, true, true).is_some());
+    }
+
+    #[test]
+    fn test_paynow_mobile() {
+        let config = PayNowConfig {
+            proxy_type: PayNowProxyType::Mobile,
+            proxy_value: "91234567".to_string(),
+            amount: None,
+            amount_editable: false,
+            expiry: None,
+            merchant_name: None,
+            reference: None,
+        };
+        let result = paynow(config.clone()).unwrap();
+        assert!(result.contains("0211+65912345670301152040000"));
+
+        let invalid = PayNowConfig {
+            proxy_value: "61234567".to_string(),
+            ..config.clone()
+        };
+        assert!(matches!(
+            paynow(invalid),
+            Err(PromptParseError::InvalidMobileNumber)
+        ));
+
+        let invalid = PayNowConfig {
+            expiry: Some("20240230".to_string()),
+            ..config
+        };
+        assert!(matches!(
+            paynow(invalid),
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::error::PromptParseError;
+use crate::generate::merchant::MerchantQrBuilder;
+use crate::generate::tip::TipPolicy;
+use crate::spec;
+use crate::tlv::{tag, TlvTag};
+use crate::Result;
+
+/// Globally unique identifier of the national QRIS template (Tag 51)
+pub const QRIS_GUID: &str = "ID.CO.QRIS.WWW";
+
+/// Merchant criteria by business size (Tags 26-45 and 51, sub-tag 03)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MerchantCriteria {
+    /// Micro business (`UMI`)
+    Micro,
+    /// Small business (`UKE`)
+    Small,
+    /// Medium business (`UME`)
+    Medium,
+    /// Large business (`UBE`)
+    Large,
+    /// Regular, e.g. government or public services (`URE`)
+    Regular,
+}
+
+impl MerchantCriteria {
+    /// Code written in the QR
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            MerchantCriteria::Micro => "UMI",
+            MerchantCriteria::Small => "UKE",
+            MerchantCriteria::Medium => "UME",
+            MerchantCriteria::Large => "UBE",
+            MerchantCriteria::Regular => "URE",
+        }
+    }
+
+    /// Get merchant criteria from its code
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "UMI" => Some(MerchantCriteria::Micro),
+            "UKE" => Some(MerchantCriteria::Small),
+            "UME" => Some(MerchantCriteria::Medium),
+            "UBE" => Some(MerchantCriteria::Large),
+            "URE" => Some(MerchantCriteria::Regular),
+            _ => None,
+        }
+    }
+}
+
+/// Merchant account template of an acquirer (Tags 26-45)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QrisAccount {
+    /// Tag ID (26-45)
+    pub tag_id: String,
+    /// Acquirer domain in reverse order (e.g. `ID.CO.BANKMANDIRI.WWW`)
+    pub acquirer: String,
+    /// Merchant PAN (sub-tag 01)
+    pub mpan: Option<String>,
+    /// Merchant ID at the acquirer (sub-tag 02)
+    pub merchant_id: Option<String>,
+    /// Merchant criteria (sub-tag 03)
+    pub merchant_criteria: Option<MerchantCriteria>,
+}
+
+impl QrisAccount {
+    pub(crate) fn to_tags(&self) -> Vec<TlvTag> {
+        let mut tags = vec![tag("00", &self.acquirer)];
+        if let Some(mpan) = &self.mpan {
+            tags.push(tag("01", mpan));
+        }
+        if let Some(merchant_id) = &self.merchant_id {
+            tags.push(tag("02", merchant_id));
+        }
+        if let Some(criteria) = self.merchant_criteria {
+            tags.push(tag("03", criteria.to_code()));
+        }
+        tags
+    }
+}
+
+/// Configuration of a static or dynamic QRIS
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QrisConfig {
+    /// Merchant account templates, at least one
+    pub accounts: Vec<QrisAccount>,
+    /// National Merchant ID (Tag 51.02, e.g. `ID1020012345678`)
+    pub nmid: String,
+    /// Merchant criteria (Tag 51.03)
+    pub merchant_criteria: MerchantCriteria,
+    /// Merchant Category Code (Tag 52)
+    pub merchant_category_code: String,
+    /// Merchant name (Tag 59)
+    pub merchant_name: String,
+    /// Merchant city (Tag 60)
+    pub merchant_city: String,
+    /// Postal code (Tag 61)
+    pub postal_code: Option<String>,
+    /// Transaction amount in IDR, makes the QR dynamic
+    pub amount: Option<Amount>,
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub tip: Option<TipPolicy>,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+/// Generate Indonesia QRIS QR Code
+///
+/// Static without an amount, dynamic with one
+pub fn qris(config: QrisConfig) -> Result<String> {
+    if config.accounts.is_empty() {
+        return Err(PromptParseError::MissingRequiredField(
+            "Merchant Account Information (Tags 26-45)".to_string(),
+        ));
+    }
+    if config.nmid.is_empty() {
+        return Err(PromptParseError::MissingRequiredField(
+            "National Merchant ID (Tag 51.02)".to_string(),
+        ));
+    }
+
+    let mut builder = MerchantQrBuilder::new();
+    for account in &config.accounts {
+        if !matches!(spec::parse_id(&account.tag_id), Some(26..=45)) {
+            return Err(PromptParseError::InvalidTagValue {
+                tag_id: account.tag_id.clone(),
+                rule: "QRIS merchant accounts must use tags 26-45".to_string(),
+            });
+        }
+        builder = builder.merchant_account(&account.tag_id, account.to_tags());
+    }
+
+    builder = builder
+        .merchant_account(
+            "51",
+            vec![
+                tag("00", QRIS_GUID),
+                tag("02", &config.nmid),
+                tag("03", config.merchant_criteria.to_code()),
+            ],
+        )
+        .merchant_category_code(&config.merchant_category_code)
+        .currency("360")
+        .country("ID")
+        .merchant_name(&config.merchant_name)
+        .merchant_city(&config.merchant_city);
+
+    if let Some(postal_code) = &config.postal_code {
+        builder = builder.postal_code(postal_code);
+    }
+    if let Some(amount) = config.amount {
+        builder = builder.amount(amount);
+    }
+    if let Some(tip) = config.tip {
+        builder = builder.tip(tip);
+    }
+    if let Some(additional_data) = config.additional_data {
+        builder = builder.additional_data(additional_data);
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> QrisConfig {
+        QrisConfig {
+            accounts: vec![QrisAccount {
+                tag_id: "26".to_string(),
+                acquirer: "ID.CO.BANKMANDIRI.WWW".to_string(),
+                mpan: Some("936000080000000123".to_string()),
+                merchant_id: Some("000000000123".to_string()),
+                merchant_criteria: Some(MerchantCriteria::Micro),
+            }],
+            nmid: "ID1020012345678".to_string(),
+            merchant_criteria: MerchantCriteria::Micro,
+            merchant_category_code: "5812".to_string(),
+            merchant_name: "WARUNG MAKAN".to_string(),
+            merchant_city: "JAKARTA".to_string(),
+            postal_code: Some("10110".to_string()),
+            amount: None,
+            tip: None,
+            additional_data: None,
+        }
+    }
+
+    #[test]
+    fn test_qris() {
+        let payload = qris(config()).unwrap();
+        assert!(payload.starts_with("000201010211"));
+        assert!(payload.contains("51440014ID.CO.QRIS.WWW0215ID10200123456780303UMI"));
+        assert!(payload.contains("5303360"));
+
+        let payload = qris(QrisConfig {
+            amount: Some("25000".parse().unwrap()),
+            tip: Some(TipPolicy::Fixed("1000".parse().unwrap())),
+            ..config()
+        })
+        .unwrap();
+        assert!(payload.starts_with("000201010212"));
+        assert!(payload.contains("54052500055020256071000.005802ID"));
+    }
+
+    #[test]
+    fn test_qris_invalid() {
+        assert!(matches!(
+            qris(QrisConfig {
+                accounts: vec![],
+                ..config()
+            }),
+            Err(PromptParseError::MissingRequiredField(_))
+        ));
+
+        let mut config = config();
+        config.accounts[0].tag_id = "46".to_string();
+        assert!(matches!(
+            qris(config),
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) if tag_id == "46"
+        ));
+    }
+}
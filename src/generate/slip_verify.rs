@@ -1,6 +1,8 @@
+use crate::bank::Bank;
 use crate::tlv::{encode, tag, with_crc_tag};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlipVerifyConfig {
     /// Bank code
     pub sending_bank: String,
@@ -8,6 +10,21 @@ pub struct SlipVerifyConfig {
     pub trans_ref: String,
 }
 
+impl SlipVerifyConfig {
+    /// Create config for a bank from the bank directory
+    pub fn new(sending_bank: Bank, trans_ref: &str) -> Self {
+        Self {
+            sending_bank: sending_bank.into(),
+            trans_ref: trans_ref.to_string(),
+        }
+    }
+
+    /// Sending bank looked up in the bank directory
+    pub fn bank(&self) -> Bank {
+        Bank::from_code(&self.sending_bank)
+    }
+}
+
 /// Generate Slip Verify QR Code
 ///
 /// This also called "Mini-QR" that embedded in slip used for verify transactions
@@ -50,4 +67,17 @@ mod tests {
         assert!(result.contains("0103014"));
         assert!(result.contains("00111222233344ABCD12"));
     }
+
+    #[test]
+    fn test_slip_verify_bank() {
+        let config = SlipVerifyConfig::new(Bank::Bbl, "0002123123121200011");
+        assert_eq!(config.bank(), Bank::Bbl);
+        assert_eq!(
+            slip_verify(config),
+            "004000060000010103002021900021231231212000115102TH91049C30"
+        );
+
+        let config = SlipVerifyConfig::new(Bank::Other("099".to_string()), "REF");
+        assert_eq!(config.sending_bank, "099");
+    }
 }
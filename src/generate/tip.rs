@@ -0,0 +1,172 @@
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::error::PromptParseError;
+use crate::spec;
+use crate::tlv::{tag, TlvTag};
+use crate::Result;
+
+/// Tip or convenience fee of a QR Code (Tags 55, 56 and 57)
+///
+/// With the `serde` feature, serialized as `{"type": "prompt"}`,
+/// `{"type": "fixed", "value": "5.00"}` or `{"type": "percentage", "value": "3.5"}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum TipPolicy {
+    /// Payer is prompted to enter a tip (`55=01`)
+    Prompt,
+    /// Fixed convenience fee (`55=02`, Tag 56)
+    Fixed(Amount),
+    /// Percentage convenience fee (`55=03`, Tag 57), e.g. `3.5` for 3.5%
+    Percentage(Amount),
+}
+
+impl TipPolicy {
+    /// Tip or Convenience Indicator (Tag 55) value
+    pub fn indicator(&self) -> &'static str {
+        match self {
+            TipPolicy::Prompt => "01",
+            TipPolicy::Fixed(_) => "02",
+            TipPolicy::Percentage(_) => "03",
+        }
+    }
+
+    /// Generate Tag 55 and the matching Tag 56 or 57
+    ///
+    /// # Arguments
+    /// * `decimals` - Decimal places of the transaction currency
+    ///
+    /// # Returns
+    /// Tags or error if the fee does not fit the currency or the percentage
+    /// is not between 0 and 100
+    pub fn to_tags(&self, decimals: u8) -> Result<Vec<TlvTag>> {
+        let mut tags = vec![tag(spec::TIP_OR_CONVENIENCE_INDICATOR, self.indicator())];
+
+        match self {
+            TipPolicy::Prompt => {}
+            TipPolicy::Fixed(fee) => {
+                tags.push(tag(
+                    spec::CONVENIENCE_FEE_FIXED,
+                    &fee.rescale(decimals)?.to_string(),
+                ));
+            }
+            TipPolicy::Percentage(percentage) => {
+                let value = percentage.to_string();
+                check_percentage(&value)?;
+                tags.push(tag(spec::CONVENIENCE_FEE_PERCENTAGE, &value));
+            }
+        }
+
+        Ok(tags)
+    }
+
+    /// Read tip policy from Tags 55, 56 and 57 of a parsed QR
+    ///
+    /// # Returns
+    /// Tip policy, None if Tag 55 is missing, or error if the tags are
+    /// malformed or Tag 56/57 does not match the indicator
+    pub fn from_qr(qr: &EmvCoQr) -> Result<Option<Self>> {
+        let fixed = qr.get_tag_value(spec::CONVENIENCE_FEE_FIXED, None);
+        let percentage = qr.get_tag_value(spec::CONVENIENCE_FEE_PERCENTAGE, None);
+
+        let policy = match qr.get_tag_value(spec::TIP_OR_CONVENIENCE_INDICATOR, None) {
+            None => None,
+            Some("01") => Some(TipPolicy::Prompt),
+            Some("02") => {
+                let fee = fixed.ok_or_else(|| {
+                    PromptParseError::TagNotFound(spec::CONVENIENCE_FEE_FIXED.to_string())
+                })?;
+                Some(TipPolicy::Fixed(fee.parse()?))
+            }
+            Some("03") => {
+                let value = percentage.ok_or_else(|| {
+                    PromptParseError::TagNotFound(spec::CONVENIENCE_FEE_PERCENTAGE.to_string())
+                })?;
+                check_percentage(value)?;
+                Some(TipPolicy::Percentage(value.parse()?))
+            }
+            Some(_) => {
+                return Err(invalid(
+                    spec::TIP_OR_CONVENIENCE_INDICATOR,
+                    "indicator must be 01, 02 or 03",
+                ))
+            }
+        };
+
+        if fixed.is_some() && !matches!(policy, Some(TipPolicy::Fixed(_))) {
+            return Err(invalid(
+                spec::CONVENIENCE_FEE_FIXED,
+                "fixed convenience fee requires indicator 02",
+            ));
+        }
+
+        if percentage.is_some() && !matches!(policy, Some(TipPolicy::Percentage(_))) {
+            return Err(invalid(
+                spec::CONVENIENCE_FEE_PERCENTAGE,
+                "percentage convenience fee requires indicator 03",
+            ));
+        }
+
+        Ok(policy)
+    }
+}
+
+/// Check Value of Convenience Fee Percentage (Tag 57)
+pub(crate) fn check_percentage(value: &str) -> Result<()> {
+    let percentage: Amount = value
+        .parse()
+        .map_err(|_| invalid(spec::CONVENIENCE_FEE_PERCENTAGE, "must be a decimal number"))?;
+
+    if value.len() > 5 || percentage.is_zero() || percentage >= Amount::from_minor_units(100, 0)? {
+        return Err(invalid(
+            spec::CONVENIENCE_FEE_PERCENTAGE,
+            "must be between 0 and 100 with at most 5 characters",
+        ));
+    }
+
+    Ok(())
+}
+
+fn invalid(tag_id: &str, rule: &str) -> PromptParseError {
+    PromptParseError::InvalidTagValue {
+        tag_id: tag_id.to_string(),
+        rule: rule.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::encode;
+
+    #[test]
+    fn test_tip_policy_to_tags() {
+        assert_eq!(encode(&TipPolicy::Prompt.to_tags(2).unwrap()), "550201");
+
+        let fixed = TipPolicy::Fixed(Amount::from_minor_units(20, 0).unwrap());
+        assert_eq!(encode(&fixed.to_tags(2).unwrap()), "550202560520.00");
+
+        let percentage = TipPolicy::Percentage("3.5".parse().unwrap());
+        assert_eq!(encode(&percentage.to_tags(2).unwrap()), "55020357033.5");
+
+        let percentage = TipPolicy::Percentage("100".parse().unwrap());
+        assert!(percentage.to_tags(2).is_err());
+    }
+
+    #[test]
+    fn test_tip_policy_from_qr() {
+        let qr = |payload: &str| EmvCoQr::new(payload.to_string(), crate::decode(payload).unwrap());
+
+        assert_eq!(TipPolicy::from_qr(&qr("000201")).unwrap(), None);
+        assert_eq!(
+            TipPolicy::from_qr(&qr("55020357033.5")).unwrap(),
+            Some(TipPolicy::Percentage("3.5".parse().unwrap()))
+        );
+        assert!(TipPolicy::from_qr(&qr("550201560520.00")).is_err());
+        assert!(TipPolicy::from_qr(&qr("550202")).is_err());
+        assert!(TipPolicy::from_qr(&qr("57033.5")).is_err());
+    }
+}
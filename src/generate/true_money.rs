@@ -1,14 +1,20 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
 use crate::tlv::{encode, tag, with_crc_tag};
 use crate::utils::encoder::encode_tag81;
+use crate::Result;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrueMoneyConfig {
     /// Mobile number
     pub mobile_no: String,
     /// Transaction amount
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
     /// Personal message (Tag 81)
     pub message: Option<String>,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
 }
 
 /// Generate QR Code for TrueMoney Wallet
@@ -16,7 +22,7 @@ pub struct TrueMoneyConfig {
 /// This QR Code can also be scanned with other apps,
 /// just like a regular e-Wallet PromptPay QR
 /// but `Personal Message (Tag 81)` will be ignored.
-pub fn true_money(config: TrueMoneyConfig) -> String {
+pub fn true_money(config: TrueMoneyConfig) -> Result<String> {
     let tag29_data = vec![
         tag("00", "A000000677010111"),
         tag("03", &format!("14000{}", config.mobile_no)),
@@ -31,14 +37,19 @@ pub fn true_money(config: TrueMoneyConfig) -> String {
     ];
 
     if let Some(amount) = config.amount {
-        payload.push(tag("54", &format!("{amount:.2}")));
+        payload.push(tag("54", &amount.rescale(2)?.to_string()));
+    }
+
+    if let Some(additional_data) = &config.additional_data {
+        additional_data.validate()?;
+        payload.extend(additional_data.to_tag());
     }
 
     if let Some(message) = config.message {
         payload.push(tag("81", &encode_tag81(&message)));
     }
 
-    with_crc_tag(&encode(&payload), "63", true)
+    Ok(with_crc_tag(&encode(&payload), "63", true))
 }
 
 #[cfg(test)]
@@ -51,8 +62,9 @@ mod tests {
             mobile_no: "0801111111".to_string(),
             amount: None,
             message: None,
+            additional_data: None,
         };
-        let result = true_money(config);
+        let result = true_money(config).unwrap();
         assert_eq!(
             result,
             "00020101021129390016A000000677010111031514000080111111153037645802TH63047C0F"
@@ -63,10 +75,11 @@ mod tests {
     fn test_true_money_with_amount_and_message() {
         let config = TrueMoneyConfig {
             mobile_no: "0801111111".to_string(),
-            amount: Some(10.05),
+            amount: Some(Amount::try_from(10.05).unwrap()),
             message: Some("Hello World!".to_string()),
+            additional_data: None,
         };
-        let result = true_money(config);
+        let result = true_money(config).unwrap();
         assert_eq!(result, "00020101021229390016A000000677010111031514000080111111153037645802TH540510.05814800480065006C006C006F00200057006F0072006C006400216304F5A2");
     }
 
@@ -74,10 +87,11 @@ mod tests {
     fn test_true_money_with_amount_only() {
         let config = TrueMoneyConfig {
             mobile_no: "0801111111".to_string(),
-            amount: Some(50.0),
+            amount: Some(Amount::try_from(50.0).unwrap()),
             message: None,
+            additional_data: None,
         };
-        let result = true_money(config);
+        let result = true_money(config).unwrap();
         assert!(result.contains("540550.00"));
     }
 }
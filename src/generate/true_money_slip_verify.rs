@@ -1,6 +1,7 @@
 use crate::tlv::{encode, tag, with_crc_tag};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrueMoneySlipVerifyConfig {
     /// Event Type (Example: P2P)
     pub event_type: String,
@@ -0,0 +1,165 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::error::PromptParseError;
+use crate::tlv::{encode, tag, with_crc_tag};
+use crate::Result;
+
+/// Application ID of the NAPAS template (Tag 38)
+pub const NAPAS_AID: &str = "A000000727";
+
+/// VietQR service code (Tag 38.02)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VietQrService {
+    /// Transfer to a bank account (`QRIBFTTA`)
+    Account,
+    /// Transfer to a card number (`QRIBFTTC`)
+    Card,
+}
+
+impl VietQrService {
+    /// Service code written in Tag 38.02
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            VietQrService::Account => "QRIBFTTA",
+            VietQrService::Card => "QRIBFTTC",
+        }
+    }
+
+    /// Get service from its code
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "QRIBFTTA" => Some(VietQrService::Account),
+            "QRIBFTTC" => Some(VietQrService::Card),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration of a VietQR account or card transfer
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VietQrConfig {
+    /// 6-digit bank identification number (Tag 38.01.00)
+    pub bin: String,
+    /// Account or card number of the beneficiary (Tag 38.01.01)
+    pub account_number: String,
+    /// Transfer to an account or a card
+    pub service: VietQrService,
+    /// Transaction amount in VND
+    pub amount: Option<Amount>,
+    /// Additional Data Field Template (Tag 62), e.g. the transfer description
+    /// in Purpose of Transaction (62.08)
+    pub additional_data: Option<AdditionalData>,
+}
+
+/// Generate Vietnam VietQR (NAPAS) QR Code
+pub fn vietqr(config: VietQrConfig) -> Result<String> {
+    if config.bin.len() != 6 || !config.bin.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: "38.01.00".to_string(),
+            rule: "BIN must be 6 digits".to_string(),
+        });
+    }
+
+    let valid_account = (1..=19).contains(&config.account_number.len())
+        && config
+            .account_number
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric());
+    if !valid_account {
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: "38.01.01".to_string(),
+            rule: "account or card number must be 1-19 letters or digits".to_string(),
+        });
+    }
+
+    let beneficiary = vec![tag("00", &config.bin), tag("01", &config.account_number)];
+    let tag38_data = vec![
+        tag("00", NAPAS_AID),
+        tag("01", &encode(&beneficiary)),
+        tag("02", config.service.to_code()),
+    ];
+
+    let mut payload = vec![
+        tag("00", "01"),
+        tag("01", if config.amount.is_none() { "11" } else { "12" }),
+        tag("38", &encode(&tag38_data)),
+        tag("53", "704"),
+    ];
+
+    if let Some(amount) = config.amount {
+        payload.push(tag("54", &amount.rescale(0)?.to_string()));
+    }
+
+    payload.push(tag("58", "VN"));
+
+    if let Some(additional_data) = &config.additional_data {
+        additional_data.validate()?;
+        payload.extend(additional_data.to_tag());
+    }
+
+    Ok(with_crc_tag(&encode(&payload), "63", true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vietqr() {
+        let config = VietQrConfig {
+            bin: "970436".to_string(),
+            account_number: "0011001932418".to_string(),
+            service: VietQrService::Account,
+            amount: None,
+            additional_data: None,
+        };
+        let result = vietqr(config).unwrap();
+        assert_eq!(
+            &result[..result.len() - 4],
+            "00020101021138570010A000000727012700069704360113001100193241802\
+             08QRIBFTTA53037045802VN6304"
+        );
+
+        let config = VietQrConfig {
+            bin: "970436".to_string(),
+            account_number: "0011001932418".to_string(),
+            service: VietQrService::Account,
+            amount: Some("50000".parse().unwrap()),
+            additional_data: Some(AdditionalData {
+                purpose_of_transaction: Some("THANH TOAN".to_string()),
+                ..Default::default()
+            }),
+        };
+        let result = vietqr(config).unwrap();
+        assert!(result.starts_with("000201010212"));
+        assert!(result.contains("53037045405500005802VN62140810THANH TOAN6304"));
+    }
+
+    #[test]
+    fn test_vietqr_invalid() {
+        let config = VietQrConfig {
+            bin: "97043".to_string(),
+            account_number: "0011001932418".to_string(),
+            service: VietQrService::Card,
+            amount: None,
+            additional_data: None,
+        };
+        assert!(matches!(
+            vietqr(config.clone()),
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+
+        let config = VietQrConfig {
+            bin: "970436".to_string(),
+            amount: Some("10.5".parse().unwrap()),
+            ..config
+        };
+        assert!(matches!(
+            vietqr(config),
+            Err(PromptParseError::AmountPrecisionExceeded { max_decimals: 0 })
+        ));
+    }
+}
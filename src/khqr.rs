@@ -0,0 +1,188 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::generate::khqr::{KhqrAccount, KhqrConfig, KhqrCurrency, KhqrTimestamp};
+use crate::promptpay::template;
+use crate::tlv::get_tag;
+
+/// Cambodia KHQR (Bakong) QR data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KhqrQr {
+    /// Bakong account ID (Tag 29.00 or 30.00)
+    pub bakong_account_id: String,
+    /// Individual (Tag 29) or merchant (Tag 30) account details
+    pub account: KhqrAccount,
+    /// Merchant Category Code (Tag 52)
+    pub merchant_category_code: String,
+    /// Merchant name (Tag 59)
+    pub merchant_name: String,
+    /// Merchant city (Tag 60)
+    pub merchant_city: String,
+    /// Transaction currency (Tag 53)
+    pub currency: KhqrCurrency,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+    /// Creation and expiry time (Tag 99)
+    pub timestamp: Option<KhqrTimestamp>,
+}
+
+impl KhqrQr {
+    /// Read the KHQR fields of a parsed EMVCo QR
+    ///
+    /// Returns None if the country is not `KH`, neither Tag 29 nor Tag 30 holds
+    /// a Bakong account, or the currency is not KHR or USD
+    pub fn from_emvco(qr: &EmvCoQr) -> Option<Self> {
+        if qr.get_tag_value("58", None) != Some("KH") {
+            return None;
+        }
+
+        let (bakong_account_id, account) = if let Some(tag29) = template(qr, "29") {
+            let value = |tag_id: &str| get_tag(&tag29, tag_id, None).map(|t| t.value.clone());
+            let account = KhqrAccount::Individual {
+                account_information: value("01"),
+                acquiring_bank: value("02"),
+            };
+            (value("00")?, account)
+        } else {
+            let tag30 = template(qr, "30")?;
+            let value = |tag_id: &str| get_tag(&tag30, tag_id, None).map(|t| t.value.clone());
+            let account = KhqrAccount::Merchant {
+                merchant_id: value("01")?,
+                acquiring_bank: value("02")?,
+            };
+            (value("00")?, account)
+        };
+        if !bakong_account_id.contains('@') {
+            return None;
+        }
+
+        let amount = match qr.get_tag_value("54", None) {
+            Some(value) => Some(value.parse::<Amount>().ok()?),
+            None => None,
+        };
+        let timestamp = match template(qr, "99") {
+            Some(tag99) => {
+                let millis = |tag_id: &str| get_tag(&tag99, tag_id, None).map(|t| t.value.parse());
+                Some(KhqrTimestamp {
+                    created_at: millis("00")?.ok()?,
+                    expires_at: millis("01").transpose().ok()?,
+                })
+            }
+            None => None,
+        };
+        let value = |tag_id: &str| qr.get_tag_value(tag_id, None).map(str::to_string);
+
+        Some(KhqrQr {
+            bakong_account_id,
+            account,
+            merchant_category_code: value("52")?,
+            merchant_name: value("59")?,
+            merchant_city: value("60")?,
+            currency: KhqrCurrency::from_code(qr.get_tag_value("53", None)?)?,
+            amount,
+            is_dynamic: qr.get_tag_value("01", None) == Some("12"),
+            additional_data: qr.additional_data(),
+            timestamp,
+        })
+    }
+
+    /// Whether this KHQR belongs to a merchant (Tag 30) rather than an individual (Tag 29)
+    pub fn is_merchant(&self) -> bool {
+        matches!(self.account, KhqrAccount::Merchant { .. })
+    }
+
+    /// Whether the QR Code has expired at `now` (milliseconds since the Unix epoch)
+    ///
+    /// QR Codes without an expiry time never expire
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.timestamp
+            .is_some_and(|timestamp| timestamp.is_expired(now))
+    }
+
+    /// Convert back into a config accepted by [`crate::generate::khqr()`]
+    pub fn to_config(&self) -> KhqrConfig {
+        KhqrConfig {
+            bakong_account_id: self.bakong_account_id.clone(),
+            account: self.account.clone(),
+            merchant_category_code: self.merchant_category_code.clone(),
+            merchant_name: self.merchant_name.clone(),
+            merchant_city: self.merchant_city.clone(),
+            currency: self.currency,
+            amount: self.amount,
+            additional_data: self.additional_data.clone(),
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::khqr;
+    use crate::parser::parse;
+    use crate::tlv::with_crc_tag;
+
+    #[test]
+    fn test_khqr_individual_round_trip() {
+        let payload = with_crc_tag(
+            "00020101021229440015john_smith@devb01091234567890208Dev Bank\
+             52045999530311654061000005802KH5910John Smith6010PHNOM PENH\
+             62160112INV-2024-00199340013170000000000001131700000900000",
+            "63",
+            true,
+        );
+        let qr = KhqrQr::from_emvco(&parse(&payload, true, true).unwrap()).unwrap();
+        assert_eq!(qr.bakong_account_id, "john_smith@devb");
+        assert_eq!(
+            qr.account,
+            KhqrAccount::Individual {
+                account_information: Some("123456789".to_string()),
+                acquiring_bank: Some("Dev Bank".to_string()),
+            }
+        );
+        assert!(!qr.is_merchant());
+        assert_eq!(qr.currency, KhqrCurrency::Khr);
+        assert_eq!(qr.amount, Some("100000".parse().unwrap()));
+        assert!(qr.is_dynamic);
+        assert!(!qr.is_expired(1_700_000_000_000));
+        assert!(qr.is_expired(1_700_000_900_000));
+        assert_eq!(khqr(qr.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_khqr_merchant() {
+        let payload = khqr(KhqrConfig {
+            bakong_account_id: "coffee_shop@aclb".to_string(),
+            account: KhqrAccount::Merchant {
+                merchant_id: "M001".to_string(),
+                acquiring_bank: "ACLEDA".to_string(),
+            },
+            merchant_category_code: "5812".to_string(),
+            merchant_name: "Coffee Shop".to_string(),
+            merchant_city: "Siem Reap".to_string(),
+            currency: KhqrCurrency::Usd,
+            amount: None,
+            additional_data: None,
+            timestamp: None,
+        })
+        .unwrap();
+        let qr = KhqrQr::from_emvco(&parse(&payload, true, true).unwrap()).unwrap();
+        assert!(qr.is_merchant());
+        assert_eq!(qr.bakong_account_id, "coffee_shop@aclb");
+        assert_eq!(qr.currency, KhqrCurrency::Usd);
+        assert_eq!(qr.timestamp, None);
+        assert!(!qr.is_expired(u64::MAX));
+
+        // PromptPay also uses Tags 29 and 30
+        let promptpay = "00020101021129370016A0000006770101110113006681234567853037645802TH";
+        assert!(KhqrQr::from_emvco(
+            &parse(&with_crc_tag(promptpay, "63", true), true, true).unwrap()
+        )
+        .is_none());
+    }
+}
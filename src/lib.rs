@@ -1,17 +1,51 @@
+pub mod additional_data;
+pub mod amount;
+pub mod bank;
 pub mod bot_barcode;
+pub mod duitnow;
 pub mod emvco_qr;
 pub mod error;
 pub mod generate;
+pub mod khqr;
 pub mod parser;
+pub mod paynow;
+pub mod promptpay;
+pub mod qris;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod repair;
+#[cfg(feature = "scan")]
+pub mod scan;
+pub mod schema;
+pub mod spec;
 pub mod tlv;
 pub mod utils;
 pub mod validate;
+pub mod vietqr;
 
+pub use additional_data::AdditionalData;
+pub use amount::Amount;
+pub use bank::Bank;
 pub use bot_barcode::BotBarcode;
-pub use emvco_qr::EmvCoQr;
+pub use duitnow::DuitNowQr;
+pub use emvco_qr::{EmvCoQr, EmvCoQrRef};
 pub use error::PromptParseError;
-pub use parser::{parse, parse_barcode};
-pub use tlv::{checksum, decode, encode, get_tag, tag, with_crc_tag, TlvTag};
+pub use khqr::KhqrQr;
+pub use parser::{
+    parse, parse_barcode, parse_duitnow, parse_khqr, parse_paynow, parse_promptpay, parse_qris,
+    parse_ref, parse_vietqr, try_parse, try_parse_barcode, try_parse_ref, try_parse_ref_with,
+    try_parse_repaired, try_parse_with,
+};
+pub use paynow::PayNowQr;
+pub use promptpay::PromptPayQr;
+pub use qris::QrisQr;
+pub use repair::{Repair, RepairPolicy, Repaired};
+pub use tlv::{
+    checksum, checksum_with, decode, decode_bytes, decode_ref, decode_with, encode, get_tag,
+    get_tag_ref, tag, with_crc_tag, with_crc_tag_with, LengthUnit, TlvTag, TlvTagRef,
+};
+pub use utils::checksum::{Crc16, Crc16Digest};
+pub use vietqr::{VietQr, VietQrBank};
 
 /// Result type for the library
 pub type Result<T> = std::result::Result<T, PromptParseError>;
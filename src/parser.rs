@@ -1,54 +1,201 @@
 use crate::bot_barcode::BotBarcode;
-use crate::emvco_qr::EmvCoQr;
-use crate::tlv::{checksum, decode};
+use crate::duitnow::DuitNowQr;
+use crate::emvco_qr::{EmvCoQr, EmvCoQrRef};
+use crate::error::PromptParseError;
+use crate::khqr::KhqrQr;
+use crate::paynow::PayNowQr;
+use crate::promptpay::PromptPayQr;
+use crate::qris::QrisQr;
+use crate::repair::{RepairPolicy, Repaired};
+use crate::schema::TagSchema;
+use crate::tlv::checksum;
+use crate::vietqr::VietQr;
+use crate::Result;
 
 /// Parse any EMVCo-compatible QR Code data string
 ///
 /// # Arguments
 /// * `payload` - QR Code data string from the scanner
 /// * `strict` - Validate CRC checksum before parsing the entire string
-/// * `sub_tags` - Parse TLV Sub-tags (If exists)
+/// * `sub_tags` - Parse TLV Sub-tags of EMVCo templates (see [`TagSchema::emvco`])
 ///
 /// # Returns
 /// QR Instance with TLV Tags
 pub fn parse(payload: &str, strict: bool, sub_tags: bool) -> Option<EmvCoQr> {
+    try_parse(payload, strict, sub_tags).ok()
+}
+
+/// Parse any EMVCo-compatible QR Code data string, reporting why it failed
+///
+/// Same as [`parse`] but returns a [`PromptParseError`] describing the failure,
+/// such as a checksum mismatch or the offset of a malformed tag
+pub fn try_parse(payload: &str, strict: bool, sub_tags: bool) -> Result<EmvCoQr> {
+    try_parse_ref(payload, strict, sub_tags).map(|qr| qr.to_owned())
+}
+
+/// Parse a damaged QR Code data string after applying the repairs allowed by `policy`
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+/// * `sub_tags` - Parse TLV Sub-tags of EMVCo templates (see [`TagSchema::emvco`])
+/// * `policy` - Repairs allowed on the payload
+///
+/// # Returns
+/// QR Instance of the repaired payload and the repairs that were applied
+pub fn try_parse_repaired(
+    payload: &str,
+    strict: bool,
+    sub_tags: bool,
+    policy: &RepairPolicy,
+) -> Result<Repaired<EmvCoQr>> {
+    let repaired = policy.repair(payload);
+    Ok(Repaired {
+        value: try_parse(&repaired.value, strict, sub_tags)?,
+        repairs: repaired.repairs,
+    })
+}
+
+/// Parse any TLV QR Code data string, reading sub-tags of the templates in `schema`
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+/// * `schema` - Tag IDs to parse as templates, recursively
+///
+/// # Returns
+/// QR Instance with TLV Tags or error describing the failure
+pub fn try_parse_with(payload: &str, strict: bool, schema: &TagSchema) -> Result<EmvCoQr> {
+    try_parse_ref_with(payload, strict, schema).map(|qr| qr.to_owned())
+}
+
+/// Parse any EMVCo-compatible QR Code data string without copying tag values
+///
+/// Same as [`parse`] but the returned tags borrow slices of `payload`
+pub fn parse_ref(payload: &str, strict: bool, sub_tags: bool) -> Option<EmvCoQrRef<'_>> {
+    try_parse_ref(payload, strict, sub_tags).ok()
+}
+
+/// Same as [`parse_ref`] but reports why the payload was rejected
+pub fn try_parse_ref(payload: &str, strict: bool, sub_tags: bool) -> Result<EmvCoQrRef<'_>> {
+    if sub_tags {
+        try_parse_ref_with(payload, strict, TagSchema::emvco())
+    } else {
+        try_parse_ref_with(payload, strict, &TagSchema::new())
+    }
+}
+
+/// Same as [`try_parse_with`] but the returned tags borrow slices of `payload`
+pub fn try_parse_ref_with<'a>(
+    payload: &'a str,
+    strict: bool,
+    schema: &TagSchema,
+) -> Result<EmvCoQrRef<'a>> {
     if payload.len() < 5 {
-        return None;
+        return Err(PromptParseError::InvalidPayload);
     }
 
-    if !payload.chars().take(4).all(|c| c.is_ascii_digit()) {
-        return None;
+    if let Some(offset) = payload.chars().take(4).position(|c| !c.is_ascii_digit()) {
+        return Err(PromptParseError::InvalidTlvAt {
+            offset,
+            reason: "tag ID and length must be numeric".to_string(),
+        });
     }
 
     if strict {
-        let expected = payload[payload.len() - 4..].to_uppercase();
-        let calculated = checksum(&payload[..payload.len() - 4], true);
-        if expected != calculated {
-            return None;
+        let crc_start = payload.len() - 4;
+        if !payload.is_char_boundary(crc_start) {
+            return Err(PromptParseError::InvalidTlvAt {
+                offset: crc_start,
+                reason: "checksum is not ASCII".to_string(),
+            });
+        }
+
+        let actual = payload[crc_start..].to_uppercase();
+        let expected = checksum(&payload[..crc_start], true);
+        if expected != actual {
+            return Err(PromptParseError::ChecksumMismatch { expected, actual });
         }
     }
 
-    let mut tags = decode(payload).ok()?;
+    let tags = schema.decode(payload)?;
     if tags.is_empty() {
-        return None;
+        return Err(PromptParseError::InvalidPayload);
     }
 
-    if sub_tags {
-        for tag in &mut tags {
-            if tag.value.len() >= 5 && tag.value.chars().take(4).all(|c| c.is_ascii_digit()) {
-                if let Ok(sub) = decode(&tag.value) {
-                    if sub
-                        .iter()
-                        .all(|val| val.length > 0 && val.length == val.value.len())
-                    {
-                        tag.sub_tags = Some(sub);
-                    }
-                }
-            }
-        }
-    }
+    Ok(EmvCoQrRef::new(payload, tags))
+}
+
+/// Parse PromptPay QR Code data string into typed data
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+///
+/// # Returns
+/// Typed PromptPay QR, [`PromptPayQr::Unknown`] for other EMVCo QR
+pub fn parse_promptpay(payload: &str, strict: bool) -> Option<PromptPayQr> {
+    parse(payload, strict, true).map(PromptPayQr::from_emvco)
+}
+
+/// Parse Malaysia DuitNow QR Code
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+///
+/// # Returns
+/// Typed DuitNow QR, or None if the payload is not a DuitNow QR
+pub fn parse_duitnow(payload: &str, strict: bool) -> Option<DuitNowQr> {
+    DuitNowQr::from_emvco(&parse(payload, strict, true)?)
+}
+
+/// Parse Cambodia KHQR (Bakong) QR Code
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+///
+/// # Returns
+/// Typed KHQR, or None if the payload is not a KHQR
+pub fn parse_khqr(payload: &str, strict: bool) -> Option<KhqrQr> {
+    KhqrQr::from_emvco(&parse(payload, strict, true)?)
+}
 
-    Some(EmvCoQr::new(payload.to_string(), tags))
+/// Parse Singapore PayNow (SGQR) QR Code
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+///
+/// # Returns
+/// Typed PayNow QR, or None if the payload is not a PayNow QR
+pub fn parse_paynow(payload: &str, strict: bool) -> Option<PayNowQr> {
+    PayNowQr::from_emvco(&parse(payload, strict, true)?)
+}
+
+/// Parse Indonesia QRIS QR Code
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+///
+/// # Returns
+/// Typed QRIS, or None if the payload is not a QRIS
+pub fn parse_qris(payload: &str, strict: bool) -> Option<QrisQr> {
+    QrisQr::from_emvco(&parse(payload, strict, true)?)
+}
+
+/// Parse Vietnam VietQR (NAPAS) QR Code
+///
+/// # Arguments
+/// * `payload` - QR Code data string from the scanner
+/// * `strict` - Validate CRC checksum before parsing the entire string
+///
+/// # Returns
+/// Typed VietQR, or None if the payload is not a VietQR
+pub fn parse_vietqr(payload: &str, strict: bool) -> Option<VietQr> {
+    VietQr::from_emvco(&parse(payload, strict, true)?)
 }
 
 /// Parse barcode data string (BOT Barcode Standard)
@@ -62,9 +209,15 @@ pub fn parse_barcode(payload: &str) -> Option<BotBarcode> {
     BotBarcode::from_string(payload)
 }
 
+/// Parse barcode data string (BOT Barcode Standard), reporting why it failed
+pub fn try_parse_barcode(payload: &str) -> Result<BotBarcode> {
+    BotBarcode::try_from_string(payload)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::repair::Repair;
 
     #[test]
     fn test_parse_valid_payload() {
@@ -94,6 +247,96 @@ mod tests {
         assert!(parse(payload, true, true).is_none());
     }
 
+    #[test]
+    fn test_try_parse_checksum_mismatch() {
+        let payload =
+            "00020101021229370016A0000006770101110113006680111111153037645802TH540520.156304FFFF";
+        match try_parse(payload, true, true) {
+            Err(PromptParseError::ChecksumMismatch { expected, actual }) => {
+                assert_eq!(expected, "42BE");
+                assert_eq!(actual, "FFFF");
+            }
+            other => panic!("Expected checksum mismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_try_parse_malformed_tlv() {
+        assert!(matches!(
+            try_parse("AAAA0000", false, true),
+            Err(PromptParseError::InvalidTlvAt { offset: 0, .. })
+        ));
+        assert!(matches!(
+            try_parse("000201019911", false, true),
+            Err(PromptParseError::TagLengthOverflow { offset: 6, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_sub_tags_only_for_templates() {
+        let payload = "00020101021229370016A0000006770101110113006680111111153037645802TH5910000212345654071000.00";
+        let qr = parse(payload, false, true).unwrap();
+
+        assert!(qr.get_tag("29", None).unwrap().sub_tags.is_some());
+        assert!(qr.get_tag("59", None).unwrap().sub_tags.is_none());
+        assert!(qr.get_tag("54", None).unwrap().sub_tags.is_none());
+    }
+
+    #[test]
+    fn test_try_parse_repaired() {
+        let payload =
+            "00020101021129370016A0000006770101110113006681222333353037645802TH63041dcf\n";
+        assert!(try_parse(payload, true, true).is_err());
+
+        let repaired = try_parse_repaired(payload, true, true, &RepairPolicy::all()).unwrap();
+        assert_eq!(repaired.value.get_tag_value("63", None), Some("1DCF"));
+        assert_eq!(
+            repaired.repairs,
+            vec![Repair::TrimWhitespace, Repair::UppercaseCrc]
+        );
+    }
+
+    #[test]
+    fn test_parse_ref() {
+        let payload =
+            "00020101021229370016A0000006770101110113006680111111153037645802TH540520.15630442BE";
+        let qr = parse_ref(payload, true, true).unwrap();
+
+        assert_eq!(qr.get_tag_value("29", Some("01")), Some("0066801111111"));
+        assert_eq!(qr.get_tag_value("54", None), Some("20.15"));
+        assert_eq!(qr.to_owned(), parse(payload, true, true).unwrap());
+    }
+
+    #[test]
+    fn test_parse_promptpay() {
+        let payload =
+            "00020101021229370016A0000006770101110113006680111111153037645802TH540520.15630442BE";
+        let result = parse_promptpay(payload, true).unwrap();
+        assert!(matches!(result, PromptPayQr::AnyId(_)));
+        assert!(parse_promptpay("AAAA0000", false).is_none());
+    }
+
+    #[test]
+    fn test_parse_paynow() {
+        let payload = crate::generate::paynow(crate::generate::PayNowConfig {
+            proxy_type: crate::generate::PayNowProxyType::Mobile,
+            proxy_value: "+6591234567".to_string(),
+            amount: None,
+            amount_editable: true,
+            expiry: None,
+            merchant_name: None,
+            reference: None,
+        })
+        .unwrap();
+        let result = parse_paynow(&payload, true).unwrap();
+        assert_eq!(result.proxy_value, "+6591234567");
+        assert!(parse_paynow(
+            "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF",
+            true
+        )
+        .is_none());
+    }
+
     #[test]
     fn test_parse_barcode_valid() {
         let payload = "|099999999999990\r111222333444\r\r0";
@@ -0,0 +1,167 @@
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::error::PromptParseError;
+use crate::generate::paynow::{PayNowConfig, PayNowProxyType, PAYNOW_GUID};
+use crate::promptpay::template;
+use crate::tlv::get_tag;
+use crate::utils::date::is_valid_yyyymmdd;
+use crate::Result;
+
+/// Singapore PayNow (SGQR) QR data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayNowQr {
+    /// Proxy type
+    pub proxy_type: PayNowProxyType,
+    /// Mobile number (with `+65`) or UEN
+    pub proxy_value: String,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Payer may change the amount (Tag 26.03)
+    pub amount_editable: bool,
+    /// Last day the QR Code can be paid (`YYYYMMDD`)
+    pub expiry: Option<String>,
+    /// Merchant name (Tag 59)
+    pub merchant_name: Option<String>,
+    /// Merchant city (Tag 60)
+    pub merchant_city: Option<String>,
+    /// Bill reference (Tag 62.01)
+    pub reference: Option<String>,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+}
+
+impl PayNowQr {
+    /// Read the PayNow fields of a parsed EMVCo QR
+    ///
+    /// Returns None if Tag 26 is not a PayNow template or contains malformed values
+    pub fn from_emvco(qr: &EmvCoQr) -> Option<Self> {
+        let tag26 = template(qr, "26")?;
+        if get_tag(&tag26, "00", None)?.value != PAYNOW_GUID {
+            return None;
+        }
+
+        let proxy_type = PayNowProxyType::from_code(&get_tag(&tag26, "01", None)?.value)?;
+        let proxy_value = get_tag(&tag26, "02", None)?.value.clone();
+        let amount_editable = match get_tag(&tag26, "03", None).map(|t| t.value.as_str()) {
+            Some("1") | None => true,
+            Some("0") => false,
+            Some(_) => return None,
+        };
+        let expiry = match get_tag(&tag26, "04", None) {
+            Some(tag) if is_valid_yyyymmdd(&tag.value) => Some(tag.value.clone()),
+            Some(_) => return None,
+            None => None,
+        };
+        let amount = match qr.get_tag_value("54", None) {
+            Some(value) => Some(value.parse::<Amount>().ok()?),
+            None => None,
+        };
+
+        Some(PayNowQr {
+            proxy_type,
+            proxy_value,
+            amount,
+            amount_editable,
+            expiry,
+            merchant_name: qr.get_tag_value("59", None).map(str::to_string),
+            merchant_city: qr.get_tag_value("60", None).map(str::to_string),
+            reference: qr.additional_data().and_then(|data| data.bill_number),
+            is_dynamic: qr.get_tag_value("01", None) == Some("12"),
+        })
+    }
+
+    /// Whether the QR Code can no longer be paid on `date` (`YYYYMMDD`)
+    ///
+    /// A QR Code without an expiry date never expires. It stays valid for the
+    /// whole expiry day.
+    ///
+    /// # Returns
+    /// Whether the QR Code has expired, or error if `date` or the expiry date is
+    /// not an existing `YYYYMMDD` date
+    pub fn is_expired(&self, date: &str) -> Result<bool> {
+        if !is_valid_yyyymmdd(date) {
+            return Err(PromptParseError::ParseError(format!(
+                "date must be an existing YYYYMMDD date, got {date}"
+            )));
+        }
+
+        match self.expiry.as_deref() {
+            None => Ok(false),
+            Some(expiry) if is_valid_yyyymmdd(expiry) => Ok(date > expiry),
+            Some(_) => Err(PromptParseError::InvalidTagValue {
+                tag_id: "26.04".to_string(),
+                rule: "expiry date must be YYYYMMDD".to_string(),
+            }),
+        }
+    }
+
+    /// Convert back into a config accepted by [`crate::generate::paynow()`]
+    pub fn to_config(&self) -> PayNowConfig {
+        PayNowConfig {
+            proxy_type: self.proxy_type,
+            proxy_value: self.proxy_value.clone(),
+            amount: self.amount,
+            amount_editable: self.amount_editable,
+            expiry: self.expiry.clone(),
+            merchant_name: self.merchant_name.clone(),
+            reference: self.reference.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::paynow;
+    use crate::parser::parse;
+
+    fn config() -> PayNowConfig {
+        PayNowConfig {
+            proxy_type: PayNowProxyType::Uen,
+            proxy_value: "201403121W".to_string(),
+            amount: Some("10.50".parse().unwrap()),
+            amount_editable: false,
+            expiry: Some("20241231".to_string()),
+            merchant_name: Some("ACME PTE LTD".to_string()),
+            reference: Some("INV001".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_paynow_round_trip() {
+        let payload = paynow(config()).unwrap();
+        let qr = PayNowQr::from_emvco(&parse(&payload, true, true).unwrap()).unwrap();
+        assert_eq!(qr.proxy_value, "201403121W");
+        assert!(!qr.amount_editable);
+        assert_eq!(qr.merchant_city.as_deref(), Some("Singapore"));
+        assert!(qr.is_dynamic);
+        assert_eq!(paynow(qr.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_paynow_expiry() {
+        let payload = paynow(config()).unwrap();
+        let qr = PayNowQr::from_emvco(&parse(&payload, true, true).unwrap()).unwrap();
+        assert!(!qr.is_expired("20241130").unwrap());
+        assert!(!qr.is_expired("20241231").unwrap());
+        assert!(qr.is_expired("20250101").unwrap());
+        assert!(qr.is_expired("2025-1-5").is_err());
+        assert!(qr.is_expired("20250230").is_err());
+
+        let malformed = PayNowQr {
+            expiry: Some("2024-12-31".to_string()),
+            ..qr.clone()
+        };
+        assert!(malformed.is_expired("20250101").is_err());
+
+        let qr = PayNowQr { expiry: None, ..qr };
+        assert!(!qr.is_expired("20991231").unwrap());
+    }
+
+    #[test]
+    fn test_paynow_not_paynow() {
+        let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+        assert!(PayNowQr::from_emvco(&parse(payload, true, true).unwrap()).is_none());
+    }
+}
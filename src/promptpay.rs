@@ -0,0 +1,471 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::generate::{AnyIdConfig, BillPaymentConfig, ProxyType, TipPolicy, TrueMoneyConfig};
+use crate::tlv::{decode, get_tag, TlvTag};
+use crate::utils::encoder::decode_tag81;
+
+/// Application ID of PromptPay AnyID (Tag 29)
+const ANY_ID_AID: &str = "A000000677010111";
+/// Application ID of PromptPay Bill Payment (Tag 30)
+const BILL_PAYMENT_AID: &str = "A000000677010112";
+/// Prefix of TrueMoney Wallet e-Wallet IDs
+const TRUE_MONEY_PREFIX: &str = "14000";
+
+/// PromptPay AnyID (Tag 29) QR data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnyIdQr {
+    /// Proxy type
+    pub proxy_type: ProxyType,
+    /// Recipient number (mobile numbers are in local `0XXXXXXXXX` format)
+    pub target: String,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub tip: Option<TipPolicy>,
+    /// Currency code (ISO 4217 numeric)
+    pub currency: String,
+    /// Country code (ISO 3166-1 alpha-2)
+    pub country: String,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+impl AnyIdQr {
+    /// Convert back into a config accepted by [`crate::generate::any_id()`]
+    pub fn to_config(&self) -> AnyIdConfig {
+        AnyIdConfig {
+            proxy_type: self.proxy_type,
+            target: self.target.clone(),
+            amount: self.amount,
+            tip: self.tip,
+            additional_data: self.additional_data.clone(),
+        }
+    }
+}
+
+/// PromptPay Bill Payment (Tag 30) QR data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BillPaymentQr {
+    /// Biller ID (National ID or Tax ID + Suffix)
+    pub biller_id: String,
+    /// Reference 1
+    pub ref1: String,
+    /// Reference 2
+    pub ref2: Option<String>,
+    /// (Undocumented) Reference 3
+    pub ref3: Option<String>,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub tip: Option<TipPolicy>,
+    /// Currency code (ISO 4217 numeric)
+    pub currency: String,
+    /// Country code (ISO 3166-1 alpha-2)
+    pub country: String,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+impl BillPaymentQr {
+    /// Convert back into a config accepted by [`crate::generate::bill_payment()`]
+    pub fn to_config(&self) -> BillPaymentConfig {
+        BillPaymentConfig {
+            biller_id: self.biller_id.clone(),
+            amount: self.amount,
+            ref1: self.ref1.clone(),
+            ref2: self.ref2.clone(),
+            ref3: self.ref3.clone(),
+            tip: self.tip,
+            additional_data: self.additional_data.clone(),
+        }
+    }
+}
+
+/// TrueMoney Wallet QR data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrueMoneyQr {
+    /// Mobile number in local `0XXXXXXXXX` format
+    pub mobile_no: String,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Personal message (Tag 81)
+    pub message: Option<String>,
+    /// Currency code (ISO 4217 numeric)
+    pub currency: String,
+    /// Country code (ISO 3166-1 alpha-2)
+    pub country: String,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+impl TrueMoneyQr {
+    /// Convert back into a config accepted by [`crate::generate::true_money()`]
+    pub fn to_config(&self) -> TrueMoneyConfig {
+        TrueMoneyConfig {
+            mobile_no: self.mobile_no.clone(),
+            amount: self.amount,
+            message: self.message.clone(),
+            additional_data: self.additional_data.clone(),
+        }
+    }
+}
+
+/// Typed PromptPay QR Code
+///
+/// With the `serde` feature, serialized with a `type` field of `any_id`,
+/// `bill_payment`, `true_money` or `unknown` next to the variant's fields
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum PromptPayQr {
+    /// PromptPay AnyID (Tag 29)
+    AnyId(AnyIdQr),
+    /// PromptPay Bill Payment (Tag 30)
+    BillPayment(BillPaymentQr),
+    /// TrueMoney Wallet (Tag 29 with TrueMoney e-Wallet ID)
+    TrueMoney(TrueMoneyQr),
+    /// Any other EMVCo merchant QR
+    Unknown(EmvCoQr),
+}
+
+impl PromptPayQr {
+    /// Classify a parsed EMVCo QR as one of the known PromptPay layouts
+    ///
+    /// Falls back to [`PromptPayQr::Unknown`] when the payload does not match
+    /// any known layout or contains malformed values
+    pub fn from_emvco(qr: EmvCoQr) -> Self {
+        if let Some(anyid) = Self::classify_tag29(&qr) {
+            return anyid;
+        }
+
+        if let Some(bill) = Self::classify_tag30(&qr) {
+            return PromptPayQr::BillPayment(bill);
+        }
+
+        PromptPayQr::Unknown(qr)
+    }
+
+    fn classify_tag29(qr: &EmvCoQr) -> Option<Self> {
+        let tag29 = template(qr, "29")?;
+        if get_tag(&tag29, "00", None)?.value != ANY_ID_AID {
+            return None;
+        }
+
+        let target_tag = tag29.iter().find(|t| t.id != "00")?;
+        let proxy_type = ProxyType::from_code(&target_tag.id)?;
+        let (currency, country, amount, is_dynamic) = common_fields(qr)?;
+
+        if proxy_type == ProxyType::EWalletId {
+            if let Some(mobile) = target_tag.value.strip_prefix(TRUE_MONEY_PREFIX) {
+                let message = match qr.get_tag_value("81", None) {
+                    Some(hex) => Some(decode_tag81(hex)?),
+                    None => None,
+                };
+
+                return Some(PromptPayQr::TrueMoney(TrueMoneyQr {
+                    mobile_no: mobile.to_string(),
+                    amount,
+                    message,
+                    currency,
+                    country,
+                    is_dynamic,
+                    additional_data: qr.additional_data(),
+                }));
+            }
+        }
+
+        let target = if proxy_type == ProxyType::Msisdn {
+            local_mobile_number(&target_tag.value)
+        } else {
+            target_tag.value.clone()
+        };
+
+        Some(PromptPayQr::AnyId(AnyIdQr {
+            proxy_type,
+            target,
+            amount,
+            tip: TipPolicy::from_qr(qr).ok()?,
+            currency,
+            country,
+            is_dynamic,
+            additional_data: qr.additional_data(),
+        }))
+    }
+
+    fn classify_tag30(qr: &EmvCoQr) -> Option<BillPaymentQr> {
+        let tag30 = template(qr, "30")?;
+        if get_tag(&tag30, "00", None)?.value != BILL_PAYMENT_AID {
+            return None;
+        }
+
+        let biller_id = get_tag(&tag30, "01", None)?.value.clone();
+        let ref1 = get_tag(&tag30, "02", None)?.value.clone();
+        let ref2 = get_tag(&tag30, "03", None).map(|t| t.value.clone());
+        let additional_data = qr.additional_data();
+        let ref3 = additional_data
+            .as_ref()
+            .and_then(|data| data.terminal_label.clone());
+        let (currency, country, amount, is_dynamic) = common_fields(qr)?;
+
+        Some(BillPaymentQr {
+            biller_id,
+            ref1,
+            ref2,
+            ref3,
+            amount,
+            tip: TipPolicy::from_qr(qr).ok()?,
+            currency,
+            country,
+            is_dynamic,
+            additional_data,
+        })
+    }
+
+    /// Proxy type of AnyID and TrueMoney QR
+    pub fn proxy_type(&self) -> Option<ProxyType> {
+        match self {
+            PromptPayQr::AnyId(qr) => Some(qr.proxy_type),
+            PromptPayQr::TrueMoney(_) => Some(ProxyType::EWalletId),
+            _ => None,
+        }
+    }
+
+    /// Recipient of AnyID and TrueMoney QR, or Biller ID of Bill Payment QR
+    pub fn target(&self) -> Option<&str> {
+        match self {
+            PromptPayQr::AnyId(qr) => Some(&qr.target),
+            PromptPayQr::BillPayment(qr) => Some(&qr.biller_id),
+            PromptPayQr::TrueMoney(qr) => Some(&qr.mobile_no),
+            PromptPayQr::Unknown(_) => None,
+        }
+    }
+
+    /// Transaction amount
+    pub fn amount(&self) -> Option<Amount> {
+        match self {
+            PromptPayQr::AnyId(qr) => qr.amount,
+            PromptPayQr::BillPayment(qr) => qr.amount,
+            PromptPayQr::TrueMoney(qr) => qr.amount,
+            PromptPayQr::Unknown(qr) => qr.get_tag_value("54", None)?.parse().ok(),
+        }
+    }
+
+    /// Bill Payment Reference 1
+    pub fn ref1(&self) -> Option<&str> {
+        match self {
+            PromptPayQr::BillPayment(qr) => Some(&qr.ref1),
+            _ => None,
+        }
+    }
+
+    /// Bill Payment Reference 2
+    pub fn ref2(&self) -> Option<&str> {
+        match self {
+            PromptPayQr::BillPayment(qr) => qr.ref2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Bill Payment (Undocumented) Reference 3
+    pub fn ref3(&self) -> Option<&str> {
+        match self {
+            PromptPayQr::BillPayment(qr) => qr.ref3.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub fn tip(&self) -> Option<TipPolicy> {
+        match self {
+            PromptPayQr::AnyId(qr) => qr.tip,
+            PromptPayQr::BillPayment(qr) => qr.tip,
+            PromptPayQr::TrueMoney(_) => None,
+            PromptPayQr::Unknown(qr) => TipPolicy::from_qr(qr).ok()?,
+        }
+    }
+
+    /// Additional Data Field Template (Tag 62)
+    pub fn additional_data(&self) -> Option<AdditionalData> {
+        match self {
+            PromptPayQr::AnyId(qr) => qr.additional_data.clone(),
+            PromptPayQr::BillPayment(qr) => qr.additional_data.clone(),
+            PromptPayQr::TrueMoney(qr) => qr.additional_data.clone(),
+            PromptPayQr::Unknown(qr) => qr.additional_data(),
+        }
+    }
+
+    /// Currency code (ISO 4217 numeric)
+    pub fn currency(&self) -> Option<&str> {
+        match self {
+            PromptPayQr::AnyId(qr) => Some(&qr.currency),
+            PromptPayQr::BillPayment(qr) => Some(&qr.currency),
+            PromptPayQr::TrueMoney(qr) => Some(&qr.currency),
+            PromptPayQr::Unknown(qr) => qr.get_tag_value("53", None),
+        }
+    }
+
+    /// Country code (ISO 3166-1 alpha-2)
+    pub fn country(&self) -> Option<&str> {
+        match self {
+            PromptPayQr::AnyId(qr) => Some(&qr.country),
+            PromptPayQr::BillPayment(qr) => Some(&qr.country),
+            PromptPayQr::TrueMoney(qr) => Some(&qr.country),
+            PromptPayQr::Unknown(qr) => qr.get_tag_value("58", None),
+        }
+    }
+
+    /// Whether the QR is dynamic (one-time) rather than static
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            PromptPayQr::AnyId(qr) => qr.is_dynamic,
+            PromptPayQr::BillPayment(qr) => qr.is_dynamic,
+            PromptPayQr::TrueMoney(qr) => qr.is_dynamic,
+            PromptPayQr::Unknown(qr) => qr.get_tag_value("01", None) == Some("12"),
+        }
+    }
+}
+
+impl From<EmvCoQr> for PromptPayQr {
+    fn from(qr: EmvCoQr) -> Self {
+        PromptPayQr::from_emvco(qr)
+    }
+}
+
+/// Get sub-tags of a template tag, decoding its value if it was parsed without sub-tags
+pub(crate) fn template(qr: &EmvCoQr, tag_id: &str) -> Option<Vec<TlvTag>> {
+    let tag = qr.get_tag(tag_id, None)?;
+    match &tag.sub_tags {
+        Some(sub_tags) => Some(sub_tags.clone()),
+        None => decode(&tag.value).ok(),
+    }
+}
+
+/// Extract currency, country, amount and point of initiation
+fn common_fields(qr: &EmvCoQr) -> Option<(String, String, Option<Amount>, bool)> {
+    let currency = qr.get_tag_value("53", None)?.to_string();
+    let country = qr.get_tag_value("58", None)?.to_string();
+    let amount = match qr.get_tag_value("54", None) {
+        Some(value) => Some(value.parse::<Amount>().ok()?),
+        None => None,
+    };
+    let is_dynamic = qr.get_tag_value("01", None) == Some("12");
+
+    Some((currency, country, amount, is_dynamic))
+}
+
+/// Convert `0066XXXXXXXXX` mobile number into local `0XXXXXXXXX` format
+fn local_mobile_number(value: &str) -> String {
+    match value.trim_start_matches('0').strip_prefix("66") {
+        Some(number) => format!("0{number}"),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{any_id, bill_payment, true_money};
+    use crate::parser::parse;
+
+    #[test]
+    fn test_promptpay_any_id_msisdn() {
+        let payload =
+            "00020101021229370016A0000006770101110113006680111111153037645802TH540520.15630442BE";
+        let qr = PromptPayQr::from_emvco(parse(payload, true, true).unwrap());
+
+        assert_eq!(qr.proxy_type(), Some(ProxyType::Msisdn));
+        assert_eq!(qr.target(), Some("0801111111"));
+        assert_eq!(qr.amount(), Some(Amount::from_satang(2015).unwrap()));
+        assert_eq!(qr.currency(), Some("764"));
+        assert_eq!(qr.country(), Some("TH"));
+        assert!(qr.is_dynamic());
+    }
+
+    #[test]
+    fn test_promptpay_any_id_round_trip() {
+        let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+        let PromptPayQr::AnyId(qr) = PromptPayQr::from_emvco(parse(payload, true, false).unwrap())
+        else {
+            panic!("Expected AnyID QR");
+        };
+
+        assert!(!qr.is_dynamic);
+        assert_eq!(qr.tip, None);
+        assert_eq!(any_id(qr.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_promptpay_any_id_tip_round_trip() {
+        let config = AnyIdConfig {
+            proxy_type: ProxyType::Msisdn,
+            target: "0812223333".to_string(),
+            amount: Some(Amount::from_satang(10000).unwrap()),
+            tip: Some(TipPolicy::Percentage("10".parse().unwrap())),
+            additional_data: None,
+        };
+        let payload = any_id(config).unwrap();
+        assert!(payload.contains("5406100.00550203570210"));
+
+        let qr = PromptPayQr::from_emvco(parse(&payload, true, true).unwrap());
+        assert_eq!(qr.tip(), Some(TipPolicy::Percentage("10".parse().unwrap())));
+
+        let PromptPayQr::AnyId(qr) = qr else {
+            panic!("Expected AnyID QR");
+        };
+        assert_eq!(any_id(qr.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_promptpay_bill_payment_round_trip() {
+        let payload = "00020101021130620016A000000677010112011301122334455660211CUSTOMER0010306INV00153037645802TH62070703SCB6304780E";
+        let qr = PromptPayQr::from_emvco(parse(payload, true, true).unwrap());
+
+        assert_eq!(qr.target(), Some("0112233445566"));
+        assert_eq!(qr.ref1(), Some("CUSTOMER001"));
+        assert_eq!(qr.ref2(), Some("INV001"));
+        assert_eq!(qr.ref3(), Some("SCB"));
+        assert_eq!(
+            qr.additional_data().unwrap().terminal_label.as_deref(),
+            Some("SCB")
+        );
+
+        let PromptPayQr::BillPayment(bill) = qr else {
+            panic!("Expected Bill Payment QR");
+        };
+        assert_eq!(bill_payment(bill.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_promptpay_true_money_round_trip() {
+        let payload = "00020101021229390016A000000677010111031514000080111111153037645802TH540510.05814800480065006C006C006F00200057006F0072006C006400216304F5A2";
+        let PromptPayQr::TrueMoney(qr) =
+            PromptPayQr::from_emvco(parse(payload, true, true).unwrap())
+        else {
+            panic!("Expected TrueMoney QR");
+        };
+
+        assert_eq!(qr.mobile_no, "0801111111");
+        assert_eq!(qr.message.as_deref(), Some("Hello World!"));
+        assert_eq!(true_money(qr.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_promptpay_unknown() {
+        let payload = "000411110104222202043333";
+        let qr = PromptPayQr::from_emvco(parse(payload, false, true).unwrap());
+
+        assert!(matches!(qr, PromptPayQr::Unknown(_)));
+        assert_eq!(qr.target(), None);
+        assert_eq!(qr.proxy_type(), None);
+    }
+}
@@ -0,0 +1,252 @@
+//! Indonesia QRIS (Quick Response Code Indonesian Standard)
+//!
+//! QRIS is an EMVCo Merchant-Presented Mode QR with one or more merchant
+//! account templates (Tags 26-45) and the national QRIS template (Tag 51)
+//! carrying the National Merchant ID (NMID).
+
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::error::PromptParseError;
+use crate::generate::qris::{MerchantCriteria, QrisAccount, QrisConfig, QRIS_GUID};
+use crate::generate::TipPolicy;
+use crate::parser::try_parse;
+use crate::promptpay::template;
+use crate::spec;
+use crate::tlv::{encode, get_tag, tag, with_crc_tag};
+use crate::Result;
+
+/// Typed QRIS data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QrisQr {
+    /// Merchant account templates (Tags 26-45)
+    pub accounts: Vec<QrisAccount>,
+    /// National Merchant ID (Tag 51.02)
+    pub nmid: String,
+    /// Merchant criteria (Tag 51.03)
+    pub merchant_criteria: Option<MerchantCriteria>,
+    /// Merchant Category Code (Tag 52)
+    pub merchant_category_code: String,
+    /// Merchant name (Tag 59)
+    pub merchant_name: String,
+    /// Merchant city (Tag 60)
+    pub merchant_city: String,
+    /// Postal code (Tag 61)
+    pub postal_code: Option<String>,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Tip or convenience fee (Tags 55, 56 and 57)
+    pub tip: Option<TipPolicy>,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+impl QrisQr {
+    /// Read the QRIS fields of a parsed EMVCo QR
+    ///
+    /// Returns None if Tag 51 is not a QRIS template or contains malformed values
+    pub fn from_emvco(qr: &EmvCoQr) -> Option<Self> {
+        let tag51 = template(qr, "51")?;
+        if get_tag(&tag51, "00", None)?.value != QRIS_GUID {
+            return None;
+        }
+
+        let mut accounts = Vec::new();
+        for id in 26..=45 {
+            let tag_id = id.to_string();
+            let Some(sub_tags) = template(qr, &tag_id) else {
+                continue;
+            };
+            let value = |sub_tag_id| get_tag(&sub_tags, sub_tag_id, None).map(|t| t.value.clone());
+            accounts.push(QrisAccount {
+                acquirer: value("00")?,
+                mpan: value("01"),
+                merchant_id: value("02"),
+                merchant_criteria: match value("03") {
+                    Some(code) => Some(MerchantCriteria::from_code(&code)?),
+                    None => None,
+                },
+                tag_id,
+            });
+        }
+
+        let amount = match qr.get_tag_value("54", None) {
+            Some(value) => Some(value.parse::<Amount>().ok()?),
+            None => None,
+        };
+        let value = |tag_id: &str| qr.get_tag_value(tag_id, None).map(str::to_string);
+
+        Some(QrisQr {
+            accounts,
+            nmid: get_tag(&tag51, "02", None)?.value.clone(),
+            merchant_criteria: match get_tag(&tag51, "03", None) {
+                Some(tag) => Some(MerchantCriteria::from_code(&tag.value)?),
+                None => None,
+            },
+            merchant_category_code: value("52")?,
+            merchant_name: value("59")?,
+            merchant_city: value("60")?,
+            postal_code: value("61"),
+            amount,
+            tip: TipPolicy::from_qr(qr).ok()?,
+            is_dynamic: qr.get_tag_value("01", None) == Some("12"),
+            additional_data: qr.additional_data(),
+        })
+    }
+
+    /// Acquirer domain of the first merchant account
+    pub fn acquirer(&self) -> Option<&str> {
+        self.accounts
+            .first()
+            .map(|account| account.acquirer.as_str())
+    }
+
+    /// Convert back into a config accepted by [`crate::generate::qris()`]
+    ///
+    /// Returns None if the QR has no merchant criteria in Tag 51
+    pub fn to_config(&self) -> Option<QrisConfig> {
+        Some(QrisConfig {
+            accounts: self.accounts.clone(),
+            nmid: self.nmid.clone(),
+            merchant_criteria: self.merchant_criteria?,
+            merchant_category_code: self.merchant_category_code.clone(),
+            merchant_name: self.merchant_name.clone(),
+            merchant_city: self.merchant_city.clone(),
+            postal_code: self.postal_code.clone(),
+            amount: self.amount,
+            tip: self.tip,
+            additional_data: self.additional_data.clone(),
+        })
+    }
+}
+
+/// Convert a static QRIS into a dynamic one for a fixed amount
+///
+/// Sets Point of Initiation (Tag 01) to `12`, sets Transaction Amount (Tag 54)
+/// and recomputes the CRC. Every other tag is kept as is.
+///
+/// # Arguments
+/// * `payload` - Static (or dynamic) QRIS data string
+/// * `amount` - Transaction amount in IDR, at most 2 decimal places
+pub fn to_dynamic(payload: &str, amount: Amount) -> Result<String> {
+    let qr = try_parse(payload, true, true)?;
+    if QrisQr::from_emvco(&qr).is_none() {
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: "51".to_string(),
+            rule: format!("payload is not a QRIS (expected {QRIS_GUID})"),
+        });
+    }
+    if amount.decimals() > 2 {
+        return Err(PromptParseError::AmountPrecisionExceeded { max_decimals: 2 });
+    }
+
+    let mut tags: Vec<_> = qr
+        .get_tags()
+        .iter()
+        .filter(|t| t.id != spec::TRANSACTION_AMOUNT && t.id != spec::CRC)
+        .cloned()
+        .collect();
+
+    for t in tags.iter_mut() {
+        if t.id == spec::POINT_OF_INITIATION {
+            t.value = "12".to_string();
+        }
+    }
+
+    let position = tags
+        .iter()
+        .position(|t| t.id.as_str() > spec::TRANSACTION_AMOUNT)
+        .unwrap_or(tags.len());
+    tags.insert(position, tag(spec::TRANSACTION_AMOUNT, &amount.to_string()));
+
+    Ok(with_crc_tag(&encode(&tags), spec::CRC, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::qris;
+    use crate::parser::{parse, parse_qris};
+
+    // Fixed payload with its CRC: a static QRIS with a wallet (Tag 26) and a
+    // bank (Tag 27) account template, laid out as printed merchant QRIS codes
+    // are. It was written by hand, not captured from a live merchant, as no
+    // real-world sample is vendored here
+    const STATIC_SAMPLE: &str = "00020101021126610014COM.GO-JEK.WWW01189360091434563214510210G456321451\
+        0303UMI27620013ID.CO.BNI.WWW011893600009150004821002120000000482100303UMI51440014ID.CO.QRIS.WWW\
+        0215ID10221456328700303UMI5204581253033605802ID5913WARUNG BU SRI6008SEMARANG61055013262070703A01\
+        63049BCA";
+
+    #[test]
+    fn test_qris_sample() {
+        let qr = parse_qris(STATIC_SAMPLE, true).unwrap();
+        assert_eq!(qr.accounts.len(), 2);
+        assert_eq!(qr.acquirer(), Some("COM.GO-JEK.WWW"));
+        assert_eq!(qr.accounts[1].acquirer, "ID.CO.BNI.WWW");
+        assert_eq!(qr.nmid, "ID1022145632870");
+        assert_eq!(qr.merchant_criteria, Some(MerchantCriteria::Micro));
+        assert_eq!(qr.merchant_name, "WARUNG BU SRI");
+        assert!(!qr.is_dynamic);
+        assert_eq!(qris(qr.to_config().unwrap()).unwrap(), STATIC_SAMPLE);
+
+        let dynamic = to_dynamic(STATIC_SAMPLE, "15000".parse().unwrap()).unwrap();
+        assert_eq!(
+            dynamic,
+            "00020101021226610014COM.GO-JEK.WWW01189360091434563214510210G4563214510303UMI\
+             27620013ID.CO.BNI.WWW011893600009150004821002120000000482100303UMI51440014ID.CO.QRIS.WWW\
+             0215ID10221456328700303UMI5204581253033605405150005802ID5913WARUNG BU SRI6008SEMARANG\
+             61055013262070703A016304CCAE"
+        );
+        let qr = parse_qris(&dynamic, true).unwrap();
+        assert!(qr.is_dynamic);
+        assert_eq!(qr.amount, Some("15000".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_qris_round_trip_with_tip() {
+        let mut config = parse_qris(STATIC_SAMPLE, true)
+            .unwrap()
+            .to_config()
+            .unwrap();
+        config.amount = Some("25000".parse().unwrap());
+        config.tip = Some(TipPolicy::Fixed("1000".parse().unwrap()));
+        let payload = qris(config).unwrap();
+
+        let qr = QrisQr::from_emvco(&parse(&payload, true, true).unwrap()).unwrap();
+        assert_eq!(qr.nmid, "ID1022145632870");
+        assert_eq!(qr.accounts[0].mpan.as_deref(), Some("936009143456321451"));
+        assert!(qr.is_dynamic);
+        assert_eq!(qr.tip, Some(TipPolicy::Fixed("1000".parse().unwrap())));
+        assert_eq!(qris(qr.to_config().unwrap()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_qris_to_dynamic() {
+        let dynamic = to_dynamic(STATIC_SAMPLE, "15000".parse().unwrap()).unwrap();
+
+        // Same as generating the sample with the amount set
+        let mut config = parse_qris(STATIC_SAMPLE, true)
+            .unwrap()
+            .to_config()
+            .unwrap();
+        config.amount = Some("15000".parse().unwrap());
+        assert_eq!(dynamic, qris(config).unwrap());
+
+        // Replaces an existing amount
+        let again = to_dynamic(&dynamic, "20000".parse().unwrap()).unwrap();
+        assert_eq!(
+            parse_qris(&again, true).unwrap().amount,
+            Some("20000".parse().unwrap())
+        );
+
+        let promptpay =
+            "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+        assert!(matches!(
+            to_dynamic(promptpay, "100".parse().unwrap()),
+            Err(PromptParseError::InvalidTagValue { .. })
+        ));
+    }
+}
@@ -0,0 +1,454 @@
+//! Render QR Code payloads as SVG or PNG images (requires the `render` feature)
+
+use crate::error::PromptParseError;
+use crate::generate::{any_id, bill_payment, true_money};
+use crate::generate::{AnyIdConfig, BillPaymentConfig, TrueMoneyConfig};
+use crate::Result;
+use qrcode::{Color, EcLevel, QrCode};
+use std::fmt::Write;
+
+/// Thai QR Payment frame color
+const NAVY: [u8; 3] = [0x11, 0x35, 0x66];
+const BLACK: [u8; 3] = [0x00, 0x00, 0x00];
+const WHITE: [u8; 3] = [0xff, 0xff, 0xff];
+
+/// Height of the frame header and of the logo area, in modules
+const FRAME_BAND: u32 = 6;
+
+/// Largest image width or height, in pixels
+const MAX_IMAGE_SIZE: u32 = 8192;
+
+/// QR Code error correction level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorCorrection {
+    /// Recovers ~7% of data
+    Low,
+    /// Recovers ~15% of data
+    #[default]
+    Medium,
+    /// Recovers ~25% of data
+    Quartile,
+    /// Recovers ~30% of data
+    High,
+}
+
+/// Image rendering options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Size of one module in pixels, the whole image may be at most 8192 pixels wide and high
+    pub module_size: u32,
+    /// Error correction level
+    pub error_correction: ErrorCorrection,
+    /// Width of the blank border around the code, in modules
+    pub quiet_zone: u32,
+    /// Draw the Thai QR Payment frame, with a header band and a blank area
+    /// above the code reserved for the PromptPay logo
+    pub thai_qr_frame: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            module_size: 8,
+            error_correction: ErrorCorrection::Medium,
+            quiet_zone: 4,
+            thai_qr_frame: false,
+        }
+    }
+}
+
+/// Anything that can be turned into a QR Code payload
+pub trait QrPayload {
+    fn to_payload(&self) -> Result<String>;
+}
+
+impl QrPayload for str {
+    fn to_payload(&self) -> Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl QrPayload for String {
+    fn to_payload(&self) -> Result<String> {
+        Ok(self.clone())
+    }
+}
+
+impl QrPayload for AnyIdConfig {
+    fn to_payload(&self) -> Result<String> {
+        any_id(self.clone())
+    }
+}
+
+impl QrPayload for BillPaymentConfig {
+    fn to_payload(&self) -> Result<String> {
+        bill_payment(self.clone())
+    }
+}
+
+impl QrPayload for TrueMoneyConfig {
+    fn to_payload(&self) -> Result<String> {
+        true_money(self.clone())
+    }
+}
+
+/// Filled rectangle in pixels
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    color: [u8; 3],
+}
+
+/// Image laid out as rectangles on a white background
+#[derive(Debug)]
+struct Canvas {
+    width: u32,
+    height: u32,
+    rects: Vec<Rect>,
+    /// Header band, where the SVG output writes the frame title
+    header: Option<Rect>,
+    /// Area reserved for the PromptPay logo
+    logo_area: Option<Rect>,
+}
+
+impl Canvas {
+    fn new(payload: &str, options: &RenderOptions) -> Result<Self> {
+        let ec_level = match options.error_correction {
+            ErrorCorrection::Low => EcLevel::L,
+            ErrorCorrection::Medium => EcLevel::M,
+            ErrorCorrection::Quartile => EcLevel::Q,
+            ErrorCorrection::High => EcLevel::H,
+        };
+        let code = QrCode::with_error_correction_level(payload.as_bytes(), ec_level)
+            .map_err(|e| PromptParseError::EncodingError(e.to_string()))?;
+
+        if options.module_size == 0 {
+            return Err(PromptParseError::EncodingError(
+                "module size must be at least 1 pixel".to_string(),
+            ));
+        }
+
+        let unit = options.module_size;
+        let modules = code.width() as u32;
+        let qr_size = options
+            .quiet_zone
+            .checked_mul(2)
+            .and_then(|quiet_zone| quiet_zone.checked_add(modules))
+            .and_then(|size| size.checked_mul(unit))
+            .ok_or_else(too_large)?;
+        // Frame adds a border on every side, the header band and the logo area
+        let (width, height) = if options.thai_qr_frame {
+            let width = unit
+                .checked_mul(2)
+                .and_then(|border| border.checked_add(qr_size))
+                .ok_or_else(too_large)?;
+            let height = unit
+                .checked_mul(FRAME_BAND * 2)
+                .and_then(|bands| bands.checked_add(width))
+                .ok_or_else(too_large)?;
+            (width, height)
+        } else {
+            (qr_size, qr_size)
+        };
+        // Every coordinate below is within the image, so cannot overflow
+        if width > MAX_IMAGE_SIZE || height > MAX_IMAGE_SIZE {
+            return Err(too_large());
+        }
+
+        let mut canvas = Canvas {
+            width: qr_size,
+            height: qr_size,
+            rects: Vec::new(),
+            header: None,
+            logo_area: None,
+        };
+
+        let (origin_x, origin_y) = if options.thai_qr_frame {
+            let border = unit;
+            canvas.width = width;
+            canvas.height = height;
+
+            let header = Rect {
+                x: 0,
+                y: 0,
+                width: canvas.width,
+                height: FRAME_BAND * unit,
+                color: NAVY,
+            };
+            canvas.rects.push(header);
+            canvas.rects.extend(border_rects(&canvas, border));
+            canvas.header = Some(header);
+            canvas.logo_area = Some(Rect {
+                x: border,
+                y: header.height,
+                width: qr_size,
+                height: FRAME_BAND * unit,
+                color: WHITE,
+            });
+
+            (border, header.height + FRAME_BAND * unit)
+        } else {
+            (0, 0)
+        };
+
+        let colors = code.to_colors();
+        let offset = options.quiet_zone * unit;
+        for (row, line) in colors.chunks(modules as usize).enumerate() {
+            let mut col = 0;
+            while col < line.len() {
+                if line[col] != Color::Dark {
+                    col += 1;
+                    continue;
+                }
+
+                let start = col;
+                while col < line.len() && line[col] == Color::Dark {
+                    col += 1;
+                }
+                canvas.rects.push(Rect {
+                    x: origin_x + offset + start as u32 * unit,
+                    y: origin_y + offset + row as u32 * unit,
+                    width: (col - start) as u32 * unit,
+                    height: unit,
+                    color: BLACK,
+                });
+            }
+        }
+
+        Ok(canvas)
+    }
+
+    fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        let _ = write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+            w = self.width,
+            h = self.height
+        );
+        let _ = write!(
+            svg,
+            r##"<rect width="{}" height="{}" fill="#ffffff"/>"##,
+            self.width, self.height
+        );
+
+        for rect in &self.rects {
+            let _ = write!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                rect.x,
+                rect.y,
+                rect.width,
+                rect.height,
+                hex(rect.color)
+            );
+        }
+
+        if let Some(header) = self.header {
+            let _ = write!(
+                svg,
+                r##"<text x="{}" y="{}" fill="#ffffff" font-family="sans-serif" font-weight="bold" font-size="{}" text-anchor="middle" dominant-baseline="central">THAI QR PAYMENT</text>"##,
+                header.width / 2,
+                header.height / 2,
+                header.height * 2 / 5
+            );
+        }
+
+        if let Some(logo) = self.logo_area {
+            let _ = write!(
+                svg,
+                r#"<rect id="promptpay-logo" x="{}" y="{}" width="{}" height="{}" fill="none"/>"#,
+                logo.x, logo.y, logo.width, logo.height
+            );
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    fn to_png(&self) -> Result<Vec<u8>> {
+        let stride = self.width as usize * 3;
+        let mut pixels = vec![0xff; stride * self.height as usize];
+
+        for rect in &self.rects {
+            for y in rect.y..rect.y + rect.height {
+                let row = y as usize * stride;
+                for x in rect.x..rect.x + rect.width {
+                    let i = row + x as usize * 3;
+                    pixels[i..i + 3].copy_from_slice(&rect.color);
+                }
+            }
+        }
+
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let encode_error = |e: png::EncodingError| PromptParseError::EncodingError(e.to_string());
+        let mut writer = encoder.write_header().map_err(encode_error)?;
+        writer.write_image_data(&pixels).map_err(encode_error)?;
+        writer.finish().map_err(encode_error)?;
+
+        Ok(png)
+    }
+}
+
+/// Error for an image larger than [`MAX_IMAGE_SIZE`]
+fn too_large() -> PromptParseError {
+    PromptParseError::EncodingError(format!(
+        "image must be at most {MAX_IMAGE_SIZE}x{MAX_IMAGE_SIZE} pixels, reduce the module size or quiet zone"
+    ))
+}
+
+/// Frame border around the whole canvas, below the header
+fn border_rects(canvas: &Canvas, border: u32) -> [Rect; 3] {
+    let top = FRAME_BAND * border;
+    [
+        Rect {
+            x: 0,
+            y: top,
+            width: border,
+            height: canvas.height - top,
+            color: NAVY,
+        },
+        Rect {
+            x: canvas.width - border,
+            y: top,
+            width: border,
+            height: canvas.height - top,
+            color: NAVY,
+        },
+        Rect {
+            x: 0,
+            y: canvas.height - border,
+            width: canvas.width,
+            height: border,
+            color: NAVY,
+        },
+    ]
+}
+
+fn hex(color: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+/// Render a payload or PromptPay config as an SVG document
+///
+/// # Arguments
+/// * `payload` - QR Code payload, or a config accepted by a generator
+/// * `options` - Module size, error correction, quiet zone and frame
+///
+/// # Returns
+/// SVG document or error if the payload does not fit in a QR Code
+pub fn to_svg<P: QrPayload + ?Sized>(payload: &P, options: &RenderOptions) -> Result<String> {
+    Ok(Canvas::new(&payload.to_payload()?, options)?.to_svg())
+}
+
+/// Render a payload or PromptPay config as PNG image bytes
+///
+/// # Arguments
+/// * `payload` - QR Code payload, or a config accepted by a generator
+/// * `options` - Module size, error correction, quiet zone and frame
+///
+/// # Returns
+/// PNG file contents or error if the payload does not fit in a QR Code
+pub fn to_png<P: QrPayload + ?Sized>(payload: &P, options: &RenderOptions) -> Result<Vec<u8>> {
+    Canvas::new(&payload.to_payload()?, options)?.to_png()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::ProxyType;
+
+    const PAYLOAD: &str =
+        "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+
+    fn png_size(png: &[u8]) -> (u32, u32) {
+        let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+        (width, height)
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let svg = to_svg(PAYLOAD, &RenderOptions::default()).unwrap();
+        // Version 3 (29 modules) with a 4 module quiet zone at 8px per module
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="296" height="296""#)
+        );
+        assert!(svg.ends_with("</svg>"));
+        assert!(!svg.contains("THAI QR PAYMENT"));
+    }
+
+    #[test]
+    fn test_render_png() {
+        let options = RenderOptions {
+            module_size: 4,
+            quiet_zone: 2,
+            ..Default::default()
+        };
+        let png = to_png(PAYLOAD, &options).unwrap();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(png_size(&png), (132, 132));
+    }
+
+    #[test]
+    fn test_render_thai_qr_frame() {
+        let config = AnyIdConfig {
+            proxy_type: ProxyType::Msisdn,
+            target: "0812223333".to_string(),
+            amount: None,
+            tip: None,
+            additional_data: None,
+        };
+        let options = RenderOptions {
+            module_size: 4,
+            thai_qr_frame: true,
+            ..Default::default()
+        };
+
+        let svg = to_svg(&config, &options).unwrap();
+        assert!(svg.contains("THAI QR PAYMENT"));
+        assert!(svg.contains(r#"id="promptpay-logo""#));
+
+        let png = to_png(&config, &options).unwrap();
+        assert_eq!(png_size(&png), (148 + 8, 148 + 8 + 48));
+    }
+
+    #[test]
+    fn test_render_invalid() {
+        let options = RenderOptions {
+            module_size: 0,
+            ..Default::default()
+        };
+        assert!(to_svg(PAYLOAD, &options).is_err());
+        assert!(to_svg(&"0".repeat(8000), &RenderOptions::default()).is_err());
+
+        for options in [
+            RenderOptions {
+                module_size: u32::MAX,
+                ..Default::default()
+            },
+            RenderOptions {
+                quiet_zone: u32::MAX,
+                ..Default::default()
+            },
+            RenderOptions {
+                module_size: 300,
+                thai_qr_frame: true,
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                to_png(PAYLOAD, &options),
+                Err(PromptParseError::EncodingError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use crate::tlv::checksum;
+use std::fmt;
+
+/// Fix applied to a damaged payload before parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Repair {
+    /// Remove zero-width characters and byte order marks left by copy-paste
+    StripInvisible,
+    /// Remove leading and trailing whitespace and newlines
+    TrimWhitespace,
+    /// Insert the missing `04` length of the CRC tag (e.g. `91ABCD`), if the
+    /// checksum then matches
+    InsertCrcLength,
+    /// Left-pad a CRC shorter than 4 characters with zeros (e.g. `9104BA3`),
+    /// if the checksum then matches
+    PadCrc,
+    /// Convert a lowercase CRC to uppercase
+    UppercaseCrc,
+}
+
+impl Repair {
+    const ALL: [Repair; 5] = [
+        Repair::StripInvisible,
+        Repair::TrimWhitespace,
+        Repair::InsertCrcLength,
+        Repair::PadCrc,
+        Repair::UppercaseCrc,
+    ];
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Repair::StripInvisible => "strip_invisible",
+            Repair::TrimWhitespace => "trim_whitespace",
+            Repair::InsertCrcLength => "insert_crc_length",
+            Repair::PadCrc => "pad_crc",
+            Repair::UppercaseCrc => "uppercase_crc",
+        })
+    }
+}
+
+/// Set of repairs allowed on a payload
+///
+/// The default policy allows no repairs.
+///
+/// # Example
+/// ```
+/// use promptparse::{Repair, RepairPolicy};
+///
+/// let policy = RepairPolicy::new().allow(Repair::TrimWhitespace);
+/// let repaired = policy.repair("000201\n");
+/// assert_eq!(repaired.value, "000201");
+/// assert_eq!(repaired.repairs, vec![Repair::TrimWhitespace]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepairPolicy {
+    allowed: u8,
+}
+
+/// Value produced from a repaired payload, with the repairs that were applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repaired<T> {
+    pub value: T,
+    pub repairs: Vec<Repair>,
+}
+
+/// Zero-width space, non-joiner, joiner, word joiner and byte order mark
+const INVISIBLE: [char; 5] = ['\u{200b}', '\u{200c}', '\u{200d}', '\u{2060}', '\u{feff}'];
+
+impl RepairPolicy {
+    /// Policy that allows no repairs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Policy that allows every repair
+    pub fn all() -> Self {
+        Repair::ALL
+            .into_iter()
+            .fold(Self::new(), |policy, repair| policy.allow(repair))
+    }
+
+    /// Also allow `repair`
+    pub fn allow(mut self, repair: Repair) -> Self {
+        self.allowed |= repair.bit();
+        self
+    }
+
+    pub fn allows(&self, repair: Repair) -> bool {
+        self.allowed & repair.bit() != 0
+    }
+
+    /// Apply the allowed repairs to a payload
+    ///
+    /// CRC repairs only touch the trailing tag that does not fit the TLV
+    /// structure, so the payload is otherwise left as is
+    ///
+    /// # Returns
+    /// Repaired payload and the repairs that changed it, in the order applied
+    pub fn repair(&self, payload: &str) -> Repaired<String> {
+        let mut value = payload.to_string();
+        let mut repairs = Vec::new();
+
+        for repair in Repair::ALL {
+            if !self.allows(repair) {
+                continue;
+            }
+
+            if let Some(fixed) = apply(repair, &value) {
+                if fixed != value {
+                    value = fixed;
+                    repairs.push(repair);
+                }
+            }
+        }
+
+        Repaired { value, repairs }
+    }
+}
+
+fn apply(repair: Repair, payload: &str) -> Option<String> {
+    match repair {
+        Repair::StripInvisible => Some(payload.replace(INVISIBLE, "")),
+        Repair::TrimWhitespace => Some(payload.trim().to_string()),
+        Repair::InsertCrcLength => {
+            let (head, crc_tag) = split_crc_tag(payload)?;
+            let (id, crc) = crc_tag.split_at(2);
+            let fixed = format!("{head}{id}04{crc}");
+            (crc.len() == 4 && is_hex(crc) && crc_matches(&fixed)).then_some(fixed)
+        }
+        Repair::PadCrc => {
+            let (head, crc_tag) = split_crc_tag(payload)?;
+            let crc = crc_tag.get(4..).filter(|crc| (1..4).contains(&crc.len()))?;
+            let fixed = format!("{head}{}{crc:0>4}", &crc_tag[..4]);
+            (&crc_tag[2..4] == "04" && is_hex(crc) && crc_matches(&fixed)).then_some(fixed)
+        }
+        Repair::UppercaseCrc => {
+            let crc_start = payload.len().checked_sub(4)?;
+            let crc = payload.get(crc_start..)?;
+            is_hex(crc).then(|| format!("{}{}", &payload[..crc_start], crc.to_uppercase()))
+        }
+    }
+}
+
+fn crc_matches(payload: &str) -> bool {
+    let crc_start = payload.len() - 4;
+    checksum(&payload[..crc_start], true) == payload[crc_start..].to_uppercase()
+}
+
+fn is_hex(value: &str) -> bool {
+    value.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Split off the last tag, or the trailing data which does not form a complete tag
+///
+/// Returns None if the payload is not ASCII or has no tag ID
+fn split_crc_tag(payload: &str) -> Option<(&str, &str)> {
+    if !payload.is_ascii() {
+        return None;
+    }
+
+    let mut pos = 0;
+    while pos + 4 <= payload.len() {
+        let Ok(length) = payload[pos + 2..pos + 4].parse::<usize>() else {
+            break;
+        };
+        let end = pos + 4 + length;
+        if end >= payload.len() {
+            break;
+        }
+        pos = end;
+    }
+
+    let tail = &payload[pos..];
+    (tail.len() > 2 && tail.as_bytes()[..2].iter().all(u8::is_ascii_digit))
+        .then(|| payload.split_at(pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SLIP: &str = "004100060000010103014022000111222233344ABCD126304BA3C";
+
+    #[test]
+    fn test_repair_whitespace_and_invisible() {
+        let policy = RepairPolicy::all();
+        let repaired = policy.repair(&format!("\u{feff}{}\u{200b}\r\n", SLIP));
+        assert_eq!(repaired.value, SLIP);
+        assert_eq!(
+            repaired.repairs,
+            vec![Repair::StripInvisible, Repair::TrimWhitespace]
+        );
+
+        let repaired = RepairPolicy::new().repair(&format!("{SLIP}\n"));
+        assert_eq!(repaired.value, format!("{SLIP}\n"));
+        assert!(repaired.repairs.is_empty());
+    }
+
+    #[test]
+    fn test_repair_crc_tag() {
+        let policy = RepairPolicy::all();
+
+        let repaired = policy.repair(&SLIP.replace("BA3C", "ba3c"));
+        assert_eq!(repaired.value, SLIP);
+        assert_eq!(repaired.repairs, vec![Repair::UppercaseCrc]);
+
+        let repaired = policy.repair(&SLIP.replace("6304", "63"));
+        assert_eq!(repaired.value, SLIP);
+        assert_eq!(repaired.repairs, vec![Repair::InsertCrcLength]);
+
+        let repaired = policy.repair("0025000600000101030140204REF25102TH910465A");
+        assert_eq!(
+            repaired.value,
+            "0025000600000101030140204REF25102TH9104065A"
+        );
+        assert_eq!(repaired.repairs, vec![Repair::PadCrc]);
+
+        // Structural fixes are skipped when the checksum still does not match
+        assert!(policy
+            .repair("0025000600000101030140204REF25102TH910475A")
+            .repairs
+            .is_empty());
+        assert!(policy.repair(SLIP).repairs.is_empty());
+    }
+
+    #[test]
+    fn test_repair_policy_allow() {
+        let policy = RepairPolicy::new().allow(Repair::PadCrc);
+        assert!(policy.allows(Repair::PadCrc));
+        assert!(!policy.allows(Repair::UppercaseCrc));
+        assert!(Repair::ALL.iter().all(|r| RepairPolicy::all().allows(*r)));
+    }
+}
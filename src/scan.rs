@@ -0,0 +1,324 @@
+//! Decode payloads from QR Code images (requires the `scan` feature)
+
+use crate::error::PromptParseError;
+use crate::parser::parse_promptpay;
+use crate::promptpay::PromptPayQr;
+use crate::repair::{Repair, RepairPolicy, Repaired};
+use crate::validate::{
+    bcel_one_proof, slip_verify, true_money_slip_verify, BcelOneProofData, SlipVerifyData,
+    TrueMoneySlipVerifyData,
+};
+use crate::Result;
+use std::path::Path;
+
+/// Axis-aligned rectangle around a QR Code, in pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Typed result of a scanned payload
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScannedData {
+    /// Slip Verify API Mini QR
+    SlipVerify(SlipVerifyData),
+    /// TrueMoney Slip Verify QR
+    TrueMoneySlipVerify(TrueMoneySlipVerifyData),
+    /// BCEL OneProof QR
+    BcelOneProof(BcelOneProofData),
+    /// PromptPay or any other EMVCo QR
+    PromptPay(Box<PromptPayQr>),
+    /// Not an EMVCo payload
+    Unrecognized,
+}
+
+/// QR Code found in an image
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScannedCode {
+    /// Location of the code in the image
+    pub bounds: BoundingBox,
+    /// Raw QR Code data string, as read from the image
+    pub payload: String,
+    /// Payload run through the slip validators and parser
+    pub data: ScannedData,
+    /// Repairs applied to the payload before it was classified
+    pub repairs: Vec<Repair>,
+}
+
+impl ScannedData {
+    /// Classify a payload, trying the slip validators before the PromptPay parser
+    ///
+    /// Checksums are validated strictly; use [`ScannedData::from_payload_repaired`]
+    /// to accept slips with a bad checksum
+    pub fn from_payload(payload: &str) -> Self {
+        if let Some(data) = slip_verify(payload, false) {
+            return ScannedData::SlipVerify(data);
+        }
+        if let Some(data) = true_money_slip_verify(payload) {
+            return ScannedData::TrueMoneySlipVerify(data);
+        }
+        if let Some(data) = bcel_one_proof(payload) {
+            return ScannedData::BcelOneProof(data);
+        }
+        match parse_promptpay(payload, true) {
+            Some(qr) => ScannedData::PromptPay(Box::new(qr)),
+            None => ScannedData::Unrecognized,
+        }
+    }
+
+    /// Same as [`ScannedData::from_payload`] but first applies the repairs allowed
+    /// by `policy`, e.g. [`Repair::PadCrc`] for slips printed with a bad checksum
+    ///
+    /// # Returns
+    /// Typed data with the repairs that were applied
+    pub fn from_payload_repaired(payload: &str, policy: &RepairPolicy) -> Repaired<Self> {
+        let Repaired { value, repairs } = policy.repair(payload);
+        Repaired {
+            value: Self::from_payload(&value),
+            repairs,
+        }
+    }
+}
+
+/// Greyscale image, one byte per pixel
+struct LumaImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl LumaImage {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Self::decode_png(bytes)
+        } else if bytes.starts_with(&[0xff, 0xd8]) {
+            Self::decode_jpeg(bytes)
+        } else {
+            Err(PromptParseError::InvalidImage(
+                "unsupported format, expected PNG or JPEG".to_string(),
+            ))
+        }
+    }
+
+    fn decode_png(bytes: &[u8]) -> Result<Self> {
+        let image_error = |e: png::DecodingError| PromptParseError::InvalidImage(e.to_string());
+
+        let mut decoder = png::Decoder::new(bytes);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info().map_err(image_error)?;
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).map_err(image_error)?;
+
+        let channels = match info.color_type {
+            png::ColorType::Grayscale => 1,
+            png::ColorType::GrayscaleAlpha => 2,
+            png::ColorType::Rgb => 3,
+            png::ColorType::Rgba => 4,
+            png::ColorType::Indexed => {
+                return Err(PromptParseError::InvalidImage(
+                    "indexed PNG was not expanded".to_string(),
+                ))
+            }
+        };
+
+        let pixels = buf[..info.buffer_size()]
+            .chunks_exact(info.line_size)
+            .flat_map(|line| line.chunks_exact(channels))
+            .map(|px| match px.len() {
+                1 | 2 => px[0],
+                _ => luma(px[0], px[1], px[2]),
+            })
+            .collect();
+
+        Ok(LumaImage {
+            width: info.width as usize,
+            height: info.height as usize,
+            pixels,
+        })
+    }
+
+    fn decode_jpeg(bytes: &[u8]) -> Result<Self> {
+        let mut decoder = jpeg_decoder::Decoder::new(bytes);
+        let buf = decoder
+            .decode()
+            .map_err(|e| PromptParseError::InvalidImage(e.to_string()))?;
+        let info = decoder
+            .info()
+            .ok_or_else(|| PromptParseError::InvalidImage("missing JPEG header".to_string()))?;
+
+        let pixels = match info.pixel_format {
+            jpeg_decoder::PixelFormat::L8 => buf,
+            jpeg_decoder::PixelFormat::L16 => buf
+                .chunks_exact(2)
+                .map(|px| (u16::from_ne_bytes([px[0], px[1]]) >> 8) as u8)
+                .collect(),
+            jpeg_decoder::PixelFormat::RGB24 => buf
+                .chunks_exact(3)
+                .map(|px| luma(px[0], px[1], px[2]))
+                .collect(),
+            jpeg_decoder::PixelFormat::CMYK32 => buf
+                .chunks_exact(4)
+                .map(|px| {
+                    let k = 255 - px[3] as u32;
+                    let rgb = [0, 1, 2].map(|i| ((255 - px[i] as u32) * k / 255) as u8);
+                    luma(rgb[0], rgb[1], rgb[2])
+                })
+                .collect(),
+        };
+
+        Ok(LumaImage {
+            width: info.width as usize,
+            height: info.height as usize,
+            pixels,
+        })
+    }
+}
+
+/// ITU-R BT.601 luma
+fn luma(r: u8, g: u8, b: u8) -> u8 {
+    ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+}
+
+fn bounding_box(points: &[rqrr::Point; 4]) -> BoundingBox {
+    let clamp = |v: i32| v.max(0) as u32;
+    let min_x = points.iter().map(|p| p.x).min().unwrap_or(0);
+    let max_x = points.iter().map(|p| p.x).max().unwrap_or(0);
+    let min_y = points.iter().map(|p| p.y).min().unwrap_or(0);
+    let max_y = points.iter().map(|p| p.y).max().unwrap_or(0);
+
+    BoundingBox {
+        x: clamp(min_x),
+        y: clamp(min_y),
+        width: clamp(max_x) - clamp(min_x),
+        height: clamp(max_y) - clamp(min_y),
+    }
+}
+
+/// Locate and decode every QR Code in a PNG or JPEG image
+///
+/// # Arguments
+/// * `image` - PNG or JPEG file contents
+///
+/// # Returns
+/// Decoded QR Codes with their location and typed data, in detection order.
+/// Codes which are found but cannot be read are skipped
+pub fn scan(image: &[u8]) -> Result<Vec<ScannedCode>> {
+    scan_repaired(image, &RepairPolicy::new())
+}
+
+/// Same as [`scan`] but repairs each payload as allowed by `policy` before
+/// classifying it
+///
+/// The repairs applied to each code are listed in [`ScannedCode::repairs`]
+pub fn scan_repaired(image: &[u8], policy: &RepairPolicy) -> Result<Vec<ScannedCode>> {
+    let luma = LumaImage::decode(image)?;
+    let mut prepared =
+        rqrr::PreparedImage::prepare_from_greyscale(luma.width, luma.height, |x, y| {
+            luma.pixels[y * luma.width + x]
+        });
+
+    Ok(prepared
+        .detect_grids()
+        .into_iter()
+        .filter_map(|grid| {
+            let (_, payload) = grid.decode().ok()?;
+            let Repaired {
+                value: data,
+                repairs,
+            } = ScannedData::from_payload_repaired(&payload, policy);
+            Some(ScannedCode {
+                bounds: bounding_box(&grid.bounds),
+                payload,
+                data,
+                repairs,
+            })
+        })
+        .collect())
+}
+
+/// Same as [`scan`] but reads the image from a file
+pub fn scan_file<P: AsRef<Path>>(path: P) -> Result<Vec<ScannedCode>> {
+    let image = std::fs::read(path.as_ref()).map_err(|e| {
+        PromptParseError::InvalidImage(format!("cannot read {}: {e}", path.as_ref().display()))
+    })?;
+    scan(&image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanned_data_from_payload() {
+        assert_eq!(
+            ScannedData::from_payload("004100060000010103014022000111222233344ABCD126304BA3C"),
+            ScannedData::SlipVerify(SlipVerifyData {
+                sending_bank: "014".to_string(),
+                trans_ref: "00111222233344ABCD12".to_string(),
+            })
+        );
+        match ScannedData::from_payload(
+            "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF",
+        ) {
+            ScannedData::PromptPay(qr) => assert!(matches!(*qr, PromptPayQr::AnyId(_))),
+            other => panic!("expected PromptPay, got {other:?}"),
+        }
+        assert_eq!(
+            ScannedData::from_payload("https://example.com"),
+            ScannedData::Unrecognized
+        );
+    }
+
+    #[test]
+    fn test_scanned_data_bad_crc() {
+        // Slip printed with the leading zero of its CRC (0CC8) dropped
+        let payload = "004100060000010103014022000111222233344ABCD0A6304CC8";
+        assert_eq!(
+            ScannedData::from_payload(payload),
+            ScannedData::Unrecognized
+        );
+
+        let repaired =
+            ScannedData::from_payload_repaired(payload, &RepairPolicy::new().allow(Repair::PadCrc));
+        assert!(matches!(repaired.value, ScannedData::SlipVerify(_)));
+        assert_eq!(repaired.repairs, vec![Repair::PadCrc]);
+    }
+
+    #[test]
+    fn test_scan_invalid_image() {
+        assert!(matches!(
+            scan(b"not an image"),
+            Err(PromptParseError::InvalidImage(_))
+        ));
+        assert!(matches!(
+            scan(b"\x89PNG\r\n\x1a\ntruncated"),
+            Err(PromptParseError::InvalidImage(_))
+        ));
+        assert!(scan_file("does-not-exist.png").is_err());
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn test_scan_rendered_png() {
+        use crate::render::{to_png, RenderOptions};
+
+        let payload = "004100060000010103014022000111222233344ABCD126304BA3C";
+        let options = RenderOptions {
+            module_size: 4,
+            ..Default::default()
+        };
+        let codes = scan(&to_png(payload, &options).unwrap()).unwrap();
+
+        assert_eq!(codes.len(), 1);
+        assert_eq!(codes[0].payload, payload);
+        assert!(matches!(codes[0].data, ScannedData::SlipVerify(_)));
+        assert!(codes[0].repairs.is_empty());
+
+        // 4 module quiet zone at 4px per module
+        let bounds = codes[0].bounds;
+        assert!((15..=17).contains(&bounds.x) && (15..=17).contains(&bounds.y));
+    }
+}
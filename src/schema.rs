@@ -0,0 +1,130 @@
+use crate::error::PromptParseError;
+use crate::tlv::{decode_ref, TlvTagRef};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+/// Registry of which data objects in a TLV payload are templates
+///
+/// Template values are parsed into sub-tags using their own nested schema,
+/// to any depth. Values of all other tags are left untouched.
+///
+/// ```
+/// use promptparse::schema::TagSchema;
+/// use promptparse::try_parse_with;
+///
+/// // Tag 81 of this scheme contains sub-tags, and its sub-tag 01 too
+/// let schema = TagSchema::emvco()
+///     .clone()
+///     .template("81", TagSchema::new().template("01", TagSchema::new()));
+///
+/// let qr = try_parse_with("00020181100106000202", false, &schema).unwrap();
+/// let tag81 = qr.get_tag("81", Some("01")).unwrap();
+/// assert_eq!(tag81.sub_tags.as_ref().unwrap()[0].value, "02");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagSchema {
+    templates: BTreeMap<String, TagSchema>,
+}
+
+impl TagSchema {
+    /// Schema without templates, every value is primitive
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a tag ID as template whose sub-tags follow `schema`
+    pub fn template(mut self, tag_id: &str, schema: TagSchema) -> Self {
+        self.templates.insert(tag_id.to_string(), schema);
+        self
+    }
+
+    /// Register a range of tag IDs as templates sharing `schema`
+    pub fn template_range(mut self, tag_ids: RangeInclusive<u8>, schema: TagSchema) -> Self {
+        for id in tag_ids {
+            self.templates.insert(format!("{id:02}"), schema.clone());
+        }
+        self
+    }
+
+    /// Nested schema of a template tag, or None if the tag is primitive
+    pub fn get(&self, tag_id: &str) -> Option<&TagSchema> {
+        self.templates.get(tag_id)
+    }
+
+    pub fn is_template(&self, tag_id: &str) -> bool {
+        self.templates.contains_key(tag_id)
+    }
+
+    /// EMVCo Merchant-Presented Mode schema
+    ///
+    /// Templates are Merchant Account Information (26-51), Additional Data
+    /// Field (62, with Payment System specific templates 50-99), Merchant
+    /// Information - Language (64) and Unreserved Templates (80-99)
+    pub fn emvco() -> &'static TagSchema {
+        static SCHEMA: OnceLock<TagSchema> = OnceLock::new();
+        SCHEMA.get_or_init(|| {
+            TagSchema::new()
+                .template_range(26..=51, TagSchema::new())
+                .template(
+                    "62",
+                    TagSchema::new().template_range(50..=99, TagSchema::new()),
+                )
+                .template("64", TagSchema::new())
+                .template_range(80..=99, TagSchema::new())
+        })
+    }
+
+    /// Slip Verify schema, where Tag 00 is a template
+    pub fn slip_verify() -> &'static TagSchema {
+        static SCHEMA: OnceLock<TagSchema> = OnceLock::new();
+        SCHEMA.get_or_init(|| TagSchema::new().template("00", TagSchema::new()))
+    }
+
+    /// Decode TLV string, parsing template values recursively
+    ///
+    /// Template values which are not valid TLV are left without sub-tags
+    pub fn decode<'a>(&self, payload: &'a str) -> Result<Vec<TlvTagRef<'a>>, PromptParseError> {
+        let mut tags = decode_ref(payload)?;
+
+        for tag in &mut tags {
+            if let Some(schema) = self.get(tag.id) {
+                tag.sub_tags = schema.decode(tag.value).ok();
+            }
+        }
+
+        Ok(tags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_emvco() {
+        let schema = TagSchema::emvco();
+        assert!(schema.is_template("29"));
+        assert!(schema.is_template("62"));
+        assert!(schema.is_template("99"));
+        assert!(!schema.is_template("54"));
+        assert!(!schema.is_template("59"));
+        assert!(schema.get("62").unwrap().is_template("50"));
+    }
+
+    #[test]
+    fn test_schema_decode() {
+        // Numeric merchant name and malformed template stay primitive
+        let payload = "591000021234566208INVALID!";
+        let tags = TagSchema::emvco().decode(payload).unwrap();
+        assert_eq!(tags[0].value, "0002123456");
+        assert!(tags[0].sub_tags.is_none());
+        assert!(tags[1].sub_tags.is_none());
+
+        let tags = TagSchema::emvco().decode("621550060002120101A").unwrap();
+        let tag62 = tags[0].sub_tags.as_ref().unwrap();
+        let tag62_50 = tag62[0].sub_tags.as_ref().unwrap();
+        assert_eq!(tag62_50[0].value, "12");
+        assert_eq!(tag62[1].value, "A");
+    }
+}
@@ -0,0 +1,173 @@
+/// Value format of an EMVCo data object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Numeric digits only
+    Numeric,
+    /// Alphanumeric special characters
+    AlphanumericSpecial,
+    /// Template containing nested data objects
+    Template,
+}
+
+/// Length and format rules of an EMVCo Merchant-Presented Mode data object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataObject {
+    /// Human readable name
+    pub name: &'static str,
+    /// Value format
+    pub format: Format,
+    /// Minimum value length (in characters)
+    pub min_length: usize,
+    /// Maximum value length (in characters)
+    pub max_length: usize,
+}
+
+const fn object(name: &'static str, format: Format, min: usize, max: usize) -> DataObject {
+    DataObject {
+        name,
+        format,
+        min_length: min,
+        max_length: max,
+    }
+}
+
+/// Payload Format Indicator
+pub const PAYLOAD_FORMAT_INDICATOR: &str = "00";
+/// Point of Initiation Method
+pub const POINT_OF_INITIATION: &str = "01";
+/// Merchant Category Code
+pub const MERCHANT_CATEGORY_CODE: &str = "52";
+/// Transaction Currency
+pub const TRANSACTION_CURRENCY: &str = "53";
+/// Transaction Amount
+pub const TRANSACTION_AMOUNT: &str = "54";
+/// Tip or Convenience Indicator
+pub const TIP_OR_CONVENIENCE_INDICATOR: &str = "55";
+/// Value of Convenience Fee Fixed
+pub const CONVENIENCE_FEE_FIXED: &str = "56";
+/// Value of Convenience Fee Percentage
+pub const CONVENIENCE_FEE_PERCENTAGE: &str = "57";
+/// Country Code
+pub const COUNTRY_CODE: &str = "58";
+/// Merchant Name
+pub const MERCHANT_NAME: &str = "59";
+/// Merchant City
+pub const MERCHANT_CITY: &str = "60";
+/// Postal Code
+pub const POSTAL_CODE: &str = "61";
+/// Additional Data Field Template
+pub const ADDITIONAL_DATA: &str = "62";
+/// CRC
+pub const CRC: &str = "63";
+/// Merchant Information - Language Template
+pub const MERCHANT_INFORMATION_LANGUAGE: &str = "64";
+
+/// Get rules of a root-level EMVCo MPM data object
+///
+/// # Arguments
+/// * `tag_id` - Two-digit tag ID
+///
+/// # Returns
+/// Data object rules or None if tag ID is not in range `00`-`99`
+pub fn data_object(tag_id: &str) -> Option<DataObject> {
+    use Format::*;
+
+    let id = parse_id(tag_id)?;
+    let object = match id {
+        0 => object("Payload Format Indicator", Numeric, 2, 2),
+        1 => object("Point of Initiation Method", Numeric, 2, 2),
+        2..=25 => object("Merchant Account Information", AlphanumericSpecial, 1, 99),
+        26..=51 => object("Merchant Account Information", Template, 1, 99),
+        52 => object("Merchant Category Code", Numeric, 4, 4),
+        53 => object("Transaction Currency", Numeric, 3, 3),
+        54 => object("Transaction Amount", AlphanumericSpecial, 1, 13),
+        55 => object("Tip or Convenience Indicator", Numeric, 2, 2),
+        56 => object("Value of Convenience Fee Fixed", AlphanumericSpecial, 1, 13),
+        57 => object(
+            "Value of Convenience Fee Percentage",
+            AlphanumericSpecial,
+            1,
+            5,
+        ),
+        58 => object("Country Code", AlphanumericSpecial, 2, 2),
+        59 => object("Merchant Name", AlphanumericSpecial, 1, 25),
+        60 => object("Merchant City", AlphanumericSpecial, 1, 15),
+        61 => object("Postal Code", AlphanumericSpecial, 1, 10),
+        62 => object("Additional Data Field Template", Template, 1, 99),
+        63 => object("CRC", AlphanumericSpecial, 4, 4),
+        64 => object("Merchant Information - Language Template", Template, 1, 99),
+        65..=79 => object("RFU for EMVCo", Template, 1, 99),
+        _ => object("Unreserved Template", Template, 1, 99),
+    };
+
+    Some(object)
+}
+
+/// Whether tag ID is a Merchant Account Information data object (`02`-`51`)
+pub fn is_merchant_account(tag_id: &str) -> bool {
+    matches!(parse_id(tag_id), Some(2..=51))
+}
+
+/// Parse two-digit tag ID into number
+pub(crate) fn parse_id(tag_id: &str) -> Option<u8> {
+    if tag_id.len() != 2 || !tag_id.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    tag_id.parse().ok()
+}
+
+/// Check a value against the rules of a data object
+///
+/// Returns a description of the broken rule, if any
+pub fn check_value(tag_id: &str, value: &str) -> Option<String> {
+    let Some(object) = data_object(tag_id) else {
+        return Some(format!("tag ID {tag_id} is not a two-digit number"));
+    };
+
+    let length = value.chars().count();
+    if length < object.min_length || length > object.max_length {
+        return Some(if object.min_length == object.max_length {
+            format!(
+                "{} must be {} characters, got {length}",
+                object.name, object.max_length
+            )
+        } else {
+            format!(
+                "{} must be {}-{} characters, got {length}",
+                object.name, object.min_length, object.max_length
+            )
+        });
+    }
+
+    if object.format == Format::Numeric && !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Some(format!("{} must be numeric", object.name));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_object() {
+        assert_eq!(data_object("59").unwrap().max_length, 25);
+        assert_eq!(data_object("29").unwrap().format, Format::Template);
+        assert_eq!(
+            data_object("02").unwrap().format,
+            Format::AlphanumericSpecial
+        );
+        assert!(data_object("9").is_none());
+        assert!(data_object("AB").is_none());
+    }
+
+    #[test]
+    fn test_check_value() {
+        assert!(check_value("52", "5999").is_none());
+        assert!(check_value("52", "59A9").is_some());
+        assert!(check_value("53", "76").is_some());
+        assert!(check_value("59", "A MERCHANT NAME LONGER THAN 25").is_some());
+        assert!(check_value("59", "ร้านกาแฟ").is_none());
+    }
+}
@@ -1,7 +1,14 @@
 use crate::error::PromptParseError;
-use crate::utils::checksum::crc16_xmodem;
+use crate::utils::checksum::Crc16;
 
+/// TLV Tag
+///
+/// With the `serde` feature, deserializing checks that `length` matches `value` in
+/// characters or bytes (it may be omitted) and that `sub_tags`, when present, encode
+/// to `value`
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TlvTagData"))]
 pub struct TlvTag {
     /// Tag ID
     pub id: String,
@@ -9,14 +16,62 @@ pub struct TlvTag {
     pub value: String,
     /// Sub Tags
     pub sub_tags: Option<Vec<TlvTag>>,
-    /// Tag Length
+    /// Tag Length (in characters, or as declared in the decoded payload)
     pub length: usize,
 }
 
+/// Unchecked [`TlvTag`] fields read by the deserializer
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TlvTagData {
+    id: String,
+    value: String,
+    #[serde(default)]
+    sub_tags: Option<Vec<TlvTag>>,
+    length: Option<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TlvTagData> for TlvTag {
+    type Error = PromptParseError;
+
+    fn try_from(data: TlvTagData) -> Result<Self, Self::Error> {
+        let invalid = |rule: &str| PromptParseError::InvalidTagValue {
+            tag_id: data.id.clone(),
+            rule: rule.to_string(),
+        };
+
+        if data.id.len() != 2 || !data.id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid("ID must be 2 digits"));
+        }
+
+        let chars = data.value.chars().count();
+        let length = data.length.unwrap_or(chars);
+        if length != chars && length != data.value.len() {
+            return Err(invalid("length must match the value"));
+        }
+        if length > 99 {
+            return Err(invalid("value must be at most 99 characters"));
+        }
+        if let Some(sub_tags) = &data.sub_tags {
+            if encode(sub_tags) != data.value {
+                return Err(invalid("sub tags must encode to the value"));
+            }
+        }
+
+        Ok(TlvTag {
+            id: data.id,
+            value: data.value,
+            sub_tags: data.sub_tags,
+            length,
+        })
+    }
+}
+
 impl TlvTag {
     pub fn new(id: String, value: String) -> Self {
         Self {
-            length: value.len(),
+            length: value.chars().count(),
             id,
             value,
             sub_tags: None,
@@ -25,7 +80,7 @@ impl TlvTag {
 
     pub fn with_sub_tags(id: String, value: String, sub_tags: Vec<TlvTag>) -> Self {
         Self {
-            length: value.len(),
+            length: value.chars().count(),
             id,
             value,
             sub_tags: Some(sub_tags),
@@ -33,33 +88,209 @@ impl TlvTag {
     }
 }
 
-/// Decode TLV string into array of TLV Tags
-pub fn decode(payload: &str) -> Result<Vec<TlvTag>, PromptParseError> {
-    let mut tags = Vec::new();
-    let mut idx = 0;
+/// Borrowed TLV Tag referencing slices of the decoded payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlvTagRef<'a> {
+    /// Tag ID
+    pub id: &'a str,
+    /// Tag Value
+    pub value: &'a str,
+    /// Sub Tags
+    pub sub_tags: Option<Vec<TlvTagRef<'a>>>,
+}
+
+impl<'a> TlvTagRef<'a> {
+    /// Tag Length in characters
+    pub fn length(&self) -> usize {
+        self.value.chars().count()
+    }
 
-    while idx < payload.len() {
-        if idx + 4 > payload.len() {
-            break;
+    /// Copy into an owned [`TlvTag`]
+    pub fn to_owned(&self) -> TlvTag {
+        TlvTag {
+            id: self.id.to_string(),
+            value: self.value.to_string(),
+            sub_tags: self
+                .sub_tags
+                .as_ref()
+                .map(|sub_tags| sub_tags.iter().map(TlvTagRef::to_owned).collect()),
+            length: self.length(),
         }
+    }
+}
 
-        let id = payload[idx..idx + 2].to_string();
-        let length_str = &payload[idx + 2..idx + 4];
-        let length = length_str
-            .parse::<usize>()
-            .map_err(|_| PromptParseError::InvalidTlv)?;
+/// Unit of the length field of a TLV data object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LengthUnit {
+    /// Length counts bytes of the UTF-8 encoded value
+    Bytes,
+    /// Length counts characters (Unicode scalar values), as EMVCo does for
+    /// values such as Merchant Name in alternate languages
+    #[default]
+    Chars,
+}
 
-        if idx + 4 + length > payload.len() {
-            return Err(PromptParseError::InvalidTlv);
+/// Data object read by [`RawTags`], borrowing from the decoded payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RawTag<'a> {
+    /// Byte offset of the tag ID in the payload
+    pub offset: usize,
+    pub id: &'a str,
+    /// Length as declared in the payload
+    pub length: usize,
+    pub value: &'a str,
+}
+
+/// Iterator over the data objects of a TLV string
+///
+/// Yields an error and stops at the first malformed data object
+pub(crate) struct RawTags<'a> {
+    payload: &'a str,
+    unit: LengthUnit,
+    offset: usize,
+    failed: bool,
+}
+
+impl<'a> RawTags<'a> {
+    pub fn new(payload: &'a str, unit: LengthUnit) -> Self {
+        Self {
+            payload,
+            unit,
+            offset: 0,
+            failed: false,
         }
+    }
+
+    fn read(&mut self) -> Result<RawTag<'a>, PromptParseError> {
+        let offset = self.offset;
+        let rest = &self.payload[offset..];
+        let header = rest.as_bytes();
+
+        let ascii = header.iter().take(4).take_while(|b| b.is_ascii()).count();
+        if ascii == header.len() && ascii < 4 {
+            return Err(PromptParseError::InvalidTlvAt {
+                offset,
+                reason: "truncated data object, expected tag ID and length".to_string(),
+            });
+        }
+        if ascii < 4 {
+            return Err(PromptParseError::InvalidTlvAt {
+                offset: offset + ascii,
+                reason: "tag ID and length must be ASCII".to_string(),
+            });
+        }
+
+        let id = &rest[..2];
+        if !header[2..4].iter().all(u8::is_ascii_digit) {
+            return Err(PromptParseError::InvalidTlvAt {
+                offset: offset + 2,
+                reason: format!("length of tag {id} is not numeric"),
+            });
+        }
+        let length = usize::from(header[2] - b'0') * 10 + usize::from(header[3] - b'0');
+
+        let body = &rest[4..];
+        let size = match self.unit {
+            LengthUnit::Bytes => length,
+            LengthUnit::Chars => match body.char_indices().nth(length) {
+                Some((size, _)) => size,
+                None if body.chars().count() == length => body.len(),
+                None => usize::MAX,
+            },
+        };
+
+        if size > body.len() {
+            return Err(PromptParseError::TagLengthOverflow {
+                offset,
+                tag_id: id.to_string(),
+                length,
+            });
+        }
+
+        if !body.is_char_boundary(size) {
+            return Err(PromptParseError::InvalidTlvAt {
+                offset: offset + 4 + size,
+                reason: format!("value of tag {id} ends inside a multi-byte character"),
+            });
+        }
+
+        self.offset += 4 + size;
+        Ok(RawTag {
+            offset,
+            id,
+            length,
+            value: &body[..size],
+        })
+    }
+}
+
+impl<'a> Iterator for RawTags<'a> {
+    type Item = Result<RawTag<'a>, PromptParseError>;
 
-        let value = payload[idx + 4..idx + 4 + length].to_string();
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.offset >= self.payload.len() {
+            return None;
+        }
 
-        tags.push(TlvTag::new(id, value));
-        idx += 4 + length;
+        let result = self.read();
+        self.failed = result.is_err();
+        Some(result)
     }
+}
+
+/// Decode TLV string into array of TLV Tags
+///
+/// Lengths are counted in characters, see [`decode_with`] to count bytes instead
+pub fn decode(payload: &str) -> Result<Vec<TlvTag>, PromptParseError> {
+    decode_with(payload, LengthUnit::Chars)
+}
+
+/// Decode TLV string into array of TLV Tags
+///
+/// # Arguments
+/// * `payload` - TLV string
+/// * `unit` - Unit of the length fields
+///
+/// # Returns
+/// TLV Tags or error with the offset of the first malformed data object
+pub fn decode_with(payload: &str, unit: LengthUnit) -> Result<Vec<TlvTag>, PromptParseError> {
+    RawTags::new(payload, unit)
+        .map(|raw| {
+            raw.map(|raw| TlvTag {
+                id: raw.id.to_string(),
+                value: raw.value.to_string(),
+                sub_tags: None,
+                length: raw.length,
+            })
+        })
+        .collect()
+}
+
+/// Decode TLV string into array of borrowed TLV Tags without copying values
+///
+/// Lengths are counted in characters
+pub fn decode_ref(payload: &str) -> Result<Vec<TlvTagRef<'_>>, PromptParseError> {
+    RawTags::new(payload, LengthUnit::Chars)
+        .map(|raw| {
+            raw.map(|raw| TlvTagRef {
+                id: raw.id,
+                value: raw.value,
+                sub_tags: None,
+            })
+        })
+        .collect()
+}
+
+/// Decode UTF-8 encoded TLV bytes into array of TLV Tags
+///
+/// Fails with the offset of the first invalid byte if the input is not UTF-8
+pub fn decode_bytes(payload: &[u8], unit: LengthUnit) -> Result<Vec<TlvTag>, PromptParseError> {
+    let payload = std::str::from_utf8(payload).map_err(|e| PromptParseError::InvalidTlvAt {
+        offset: e.valid_up_to(),
+        reason: "payload is not valid UTF-8".to_string(),
+    })?;
 
-    Ok(tags)
+    decode_with(payload, unit)
 }
 
 /// Encode TLV Tags array into TLV string
@@ -82,19 +313,30 @@ pub fn encode(tags: &[TlvTag]) -> String {
 
 /// Generate CRC Checksum for provided string
 pub fn checksum(payload: &str, upper_case: bool) -> String {
-    let mut sum = format!("{:x}", crc16_xmodem(payload, 0xffff));
+    checksum_with(payload.as_bytes(), &Crc16::CCITT_FALSE, upper_case)
+}
+
+/// Generate CRC Checksum for provided bytes with a specific CRC-16 variant
+pub fn checksum_with(payload: &[u8], crc: &Crc16, upper_case: bool) -> String {
+    let sum = crc.checksum(payload);
     if upper_case {
-        sum = sum.to_uppercase();
+        format!("{sum:04X}")
+    } else {
+        format!("{sum:04x}")
     }
-    format!("{sum:0>4}")
 }
 
 /// Get TLV string combined with CRC Tag
 pub fn with_crc_tag(payload: &str, crc_tag_id: &str, upper_case: bool) -> String {
+    with_crc_tag_with(payload, crc_tag_id, &Crc16::CCITT_FALSE, upper_case)
+}
+
+/// Get TLV string combined with CRC Tag, using a specific CRC-16 variant
+pub fn with_crc_tag_with(payload: &str, crc_tag_id: &str, crc: &Crc16, upper_case: bool) -> String {
     let mut result = payload.to_string();
     result.push_str(&format!("{crc_tag_id:0>2}"));
     result.push_str("04");
-    result.push_str(&checksum(&result, upper_case));
+    result.push_str(&checksum_with(result.as_bytes(), crc, upper_case));
     result
 }
 
@@ -116,6 +358,20 @@ pub fn get_tag<'a>(
     Some(tag)
 }
 
+/// Get Tag or Sub-tag by Tag ID in array of borrowed TLV Tags
+pub fn get_tag_ref<'a, 'b>(
+    tlv_tags: &'b [TlvTagRef<'a>],
+    tag_id: &str,
+    sub_tag_id: Option<&str>,
+) -> Option<&'b TlvTagRef<'a>> {
+    let tag = tlv_tags.iter().find(|t| t.id == tag_id)?;
+
+    match sub_tag_id {
+        Some(sub_id) => tag.sub_tags.as_ref()?.iter().find(|s| s.id == sub_id),
+        None => Some(tag),
+    }
+}
+
 /// Create new TLV Tag
 pub fn tag(tag_id: &str, value: &str) -> TlvTag {
     TlvTag::new(tag_id.to_string(), value.to_string())
@@ -149,6 +405,17 @@ mod tests {
         assert_eq!(result, "42BE");
     }
 
+    #[test]
+    fn test_checksum_with() {
+        let payload = b"00020101021129370016A0000006770101110113006681222333353037645802TH6304";
+        assert_eq!(checksum_with(payload, &Crc16::CCITT_FALSE, true), "1DCF");
+        assert_eq!(checksum_with(b"123456789", &Crc16::KERMIT, false), "2189");
+        assert_eq!(
+            with_crc_tag_with("000201", "63", &Crc16::XMODEM, true),
+            format!("0002016304{:04X}", Crc16::XMODEM.checksum(b"0002016304"))
+        );
+    }
+
     #[test]
     fn test_with_crc_tag() {
         let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH";
@@ -169,4 +436,75 @@ mod tests {
         let not_found = get_tag(&tags, "99", None);
         assert!(not_found.is_none());
     }
+
+    #[test]
+    fn test_decode_ref() {
+        let payload = "000201010211";
+        let tags = decode_ref(payload).unwrap();
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(get_tag_ref(&tags, "01", None).unwrap().value, "11");
+        assert!(std::ptr::eq(tags[1].value, &payload[10..]));
+
+        let owned: Vec<TlvTag> = tags.iter().map(TlvTagRef::to_owned).collect();
+        assert_eq!(owned, decode(payload).unwrap());
+    }
+
+    #[test]
+    fn test_decode_positioned_errors() {
+        assert!(matches!(
+            decode("00020101XX"),
+            Err(PromptParseError::InvalidTlvAt { offset: 8, .. })
+        ));
+        assert!(matches!(
+            decode("0002010120AB"),
+            Err(PromptParseError::TagLengthOverflow {
+                offset: 6,
+                length: 20,
+                ..
+            })
+        ));
+        assert!(matches!(
+            decode("000201010"),
+            Err(PromptParseError::InvalidTlvAt { offset: 6, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_utf8() {
+        let payload = encode(&[tag("00", "01"), tag("59", "ร้านกาแฟ"), tag("60", "BKK")]);
+        assert!(payload.starts_with("0002015908"));
+
+        let tags = decode(&payload).unwrap();
+        assert_eq!(tags[1].value, "ร้านกาแฟ");
+        assert_eq!(tags[2].value, "BKK");
+        assert_eq!(
+            decode_bytes(payload.as_bytes(), LengthUnit::Chars).unwrap(),
+            tags
+        );
+
+        let tags = decode_with("0006กข", LengthUnit::Bytes).unwrap();
+        assert_eq!(tags[0].value, "กข");
+        assert!(matches!(
+            decode_with("0004กข", LengthUnit::Bytes),
+            Err(PromptParseError::InvalidTlvAt { offset: 8, .. })
+        ));
+        assert!(matches!(
+            decode_bytes(b"0002\xff\xfe", LengthUnit::Bytes),
+            Err(PromptParseError::InvalidTlvAt { offset: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_never_panics() {
+        for payload in [
+            "0", "00", "0ก", "00กข", "0001", "00+1X", "0099ก", "0ก01", "0002ก",
+        ] {
+            assert!(decode(payload).is_err(), "{payload}");
+            assert!(
+                decode_with(payload, LengthUnit::Bytes).is_err(),
+                "{payload}"
+            );
+        }
+    }
 }
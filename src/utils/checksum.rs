@@ -1,45 +1,185 @@
-// CRC-16-CCITT (XMODEM)
-// Polynomial = 0x1021
-
-const TABLE: [u16; 256] = [
-    0x0000, 0x1021, 0x2042, 0x3063, 0x4084, 0x50a5, 0x60c6, 0x70e7, 0x8108, 0x9129, 0xa14a, 0xb16b,
-    0xc18c, 0xd1ad, 0xe1ce, 0xf1ef, 0x1231, 0x0210, 0x3273, 0x2252, 0x52b5, 0x4294, 0x72f7, 0x62d6,
-    0x9339, 0x8318, 0xb37b, 0xa35a, 0xd3bd, 0xc39c, 0xf3ff, 0xe3de, 0x2462, 0x3443, 0x0420, 0x1401,
-    0x64e6, 0x74c7, 0x44a4, 0x5485, 0xa56a, 0xb54b, 0x8528, 0x9509, 0xe5ee, 0xf5cf, 0xc5ac, 0xd58d,
-    0x3653, 0x2672, 0x1611, 0x0630, 0x76d7, 0x66f6, 0x5695, 0x46b4, 0xb75b, 0xa77a, 0x9719, 0x8738,
-    0xf7df, 0xe7fe, 0xd79d, 0xc7bc, 0x48c4, 0x58e5, 0x6886, 0x78a7, 0x0840, 0x1861, 0x2802, 0x3823,
-    0xc9cc, 0xd9ed, 0xe98e, 0xf9af, 0x8948, 0x9969, 0xa90a, 0xb92b, 0x5af5, 0x4ad4, 0x7ab7, 0x6a96,
-    0x1a71, 0x0a50, 0x3a33, 0x2a12, 0xdbfd, 0xcbdc, 0xfbbf, 0xeb9e, 0x9b79, 0x8b58, 0xbb3b, 0xab1a,
-    0x6ca6, 0x7c87, 0x4ce4, 0x5cc5, 0x2c22, 0x3c03, 0x0c60, 0x1c41, 0xedae, 0xfd8f, 0xcdec, 0xddcd,
-    0xad2a, 0xbd0b, 0x8d68, 0x9d49, 0x7e97, 0x6eb6, 0x5ed5, 0x4ef4, 0x3e13, 0x2e32, 0x1e51, 0x0e70,
-    0xff9f, 0xefbe, 0xdfdd, 0xcffc, 0xbf1b, 0xaf3a, 0x9f59, 0x8f78, 0x9188, 0x81a9, 0xb1ca, 0xa1eb,
-    0xd10c, 0xc12d, 0xf14e, 0xe16f, 0x1080, 0x00a1, 0x30c2, 0x20e3, 0x5004, 0x4025, 0x7046, 0x6067,
-    0x83b9, 0x9398, 0xa3fb, 0xb3da, 0xc33d, 0xd31c, 0xe37f, 0xf35e, 0x02b1, 0x1290, 0x22f3, 0x32d2,
-    0x4235, 0x5214, 0x6277, 0x7256, 0xb5ea, 0xa5cb, 0x95a8, 0x8589, 0xf56e, 0xe54f, 0xd52c, 0xc50d,
-    0x34e2, 0x24c3, 0x14a0, 0x0481, 0x7466, 0x6447, 0x5424, 0x4405, 0xa7db, 0xb7fa, 0x8799, 0x97b8,
-    0xe75f, 0xf77e, 0xc71d, 0xd73c, 0x26d3, 0x36f2, 0x0691, 0x16b0, 0x6657, 0x7676, 0x4615, 0x5634,
-    0xd94c, 0xc96d, 0xf90e, 0xe92f, 0x99c8, 0x89e9, 0xb98a, 0xa9ab, 0x5844, 0x4865, 0x7806, 0x6827,
-    0x18c0, 0x08e1, 0x3882, 0x28a3, 0xcb7d, 0xdb5c, 0xeb3f, 0xfb1e, 0x8bf9, 0x9bd8, 0xabbb, 0xbb9a,
-    0x4a75, 0x5a54, 0x6a37, 0x7a16, 0x0af1, 0x1ad0, 0x2ab3, 0x3a92, 0xfd2e, 0xed0f, 0xdd6c, 0xcd4d,
-    0xbdaa, 0xad8b, 0x9de8, 0x8dc9, 0x7c26, 0x6c07, 0x5c64, 0x4c45, 0x3ca2, 0x2c83, 0x1ce0, 0x0cc1,
-    0xef1f, 0xff3e, 0xcf5d, 0xdf7c, 0xaf9b, 0xbfba, 0x8fd9, 0x9ff8, 0x6e17, 0x7e36, 0x4e55, 0x5e74,
-    0x2e93, 0x3eb2, 0x0ed1, 0x1ef0,
-];
+/// Parameterised CRC-16 engine (Rocksoft model)
+///
+/// EMVCo QR Codes use [`Crc16::CCITT_FALSE`]; other presets cover schemes that
+/// reflect the input or invert the result.
+///
+/// # Example
+/// ```
+/// use promptparse::Crc16;
+///
+/// let mut digest = Crc16::CCITT_FALSE.digest();
+/// digest.update(b"1234");
+/// digest.update(b"56789");
+/// assert_eq!(digest.finalize(), 0x29b1);
+/// assert_eq!(Crc16::KERMIT.checksum(b"123456789"), 0x2189);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Crc16 {
+    poly: u16,
+    init: u16,
+    refin: bool,
+    refout: bool,
+    xorout: u16,
+    table: [u16; 256],
+}
 
-pub fn crc16_xmodem(data: &str, crc: u16) -> u16 {
-    let mut crc = crc;
-    for byte in data.bytes() {
-        let n = ((byte as u16) ^ (crc >> 8)) & 0xff;
-        crc = TABLE[n as usize] ^ (crc << 8);
+impl Crc16 {
+    /// CRC-16/CCITT-FALSE (also IBM-3740), used by EMVCo QR Codes
+    pub const CCITT_FALSE: Crc16 = Crc16::new(0x1021, 0xffff, false, false, 0x0000);
+    /// CRC-16/XMODEM
+    pub const XMODEM: Crc16 = Crc16::new(0x1021, 0x0000, false, false, 0x0000);
+    /// CRC-16/KERMIT
+    pub const KERMIT: Crc16 = Crc16::new(0x1021, 0x0000, true, true, 0x0000);
+    /// CRC-16/X-25 (also IBM-SDLC)
+    pub const X25: Crc16 = Crc16::new(0x1021, 0xffff, true, true, 0xffff);
+    /// CRC-16/ARC
+    pub const ARC: Crc16 = Crc16::new(0x8005, 0x0000, true, true, 0x0000);
+    /// CRC-16/MODBUS
+    pub const MODBUS: Crc16 = Crc16::new(0x8005, 0xffff, true, true, 0x0000);
+
+    /// Create an engine from CRC parameters
+    ///
+    /// # Arguments
+    /// * `poly` - Generator polynomial, without the leading bit
+    /// * `init` - Initial register value
+    /// * `refin` - Reflect each input byte
+    /// * `refout` - Reflect the register before the final XOR
+    /// * `xorout` - Value XORed into the result
+    pub const fn new(poly: u16, init: u16, refin: bool, refout: bool, xorout: u16) -> Self {
+        let mut table = [0u16; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = (i as u16) << 8;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ poly
+                } else {
+                    crc << 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+
+        Self {
+            poly,
+            init,
+            refin,
+            refout,
+            xorout,
+            table,
+        }
+    }
+
+    /// Start an incremental checksum
+    pub fn digest(&self) -> Crc16Digest<'_> {
+        Crc16Digest {
+            crc: self,
+            value: self.init,
+        }
+    }
+
+    /// Checksum of `data` in one call
+    pub fn checksum(&self, data: &[u8]) -> u16 {
+        let mut digest = self.digest();
+        digest.update(data);
+        digest.finalize()
+    }
+
+    pub fn poly(&self) -> u16 {
+        self.poly
+    }
+
+    pub fn init(&self) -> u16 {
+        self.init
+    }
+
+    pub fn refin(&self) -> bool {
+        self.refin
+    }
+
+    pub fn refout(&self) -> bool {
+        self.refout
+    }
+
+    pub fn xorout(&self) -> u16 {
+        self.xorout
+    }
+}
+
+impl Default for Crc16 {
+    fn default() -> Self {
+        Self::CCITT_FALSE
     }
-    crc
+}
+
+impl std::fmt::Debug for Crc16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Crc16")
+            .field("poly", &format_args!("{:#06x}", self.poly))
+            .field("init", &format_args!("{:#06x}", self.init))
+            .field("refin", &self.refin)
+            .field("refout", &self.refout)
+            .field("xorout", &format_args!("{:#06x}", self.xorout))
+            .finish()
+    }
+}
+
+/// Running checksum created by [`Crc16::digest`]
+#[derive(Debug, Clone)]
+pub struct Crc16Digest<'a> {
+    crc: &'a Crc16,
+    value: u16,
+}
+
+impl Crc16Digest<'_> {
+    /// Feed more data into the checksum
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            let byte = if self.crc.refin {
+                byte.reverse_bits()
+            } else {
+                byte
+            };
+            let n = (byte ^ (self.value >> 8) as u8) as usize;
+            self.value = self.crc.table[n] ^ (self.value << 8);
+        }
+    }
+
+    /// Checksum of all data fed so far
+    pub fn finalize(&self) -> u16 {
+        let value = if self.crc.refout {
+            self.value.reverse_bits()
+        } else {
+            self.value
+        };
+        value ^ self.crc.xorout
+    }
+}
+
+/// CRC-16 with polynomial 0x1021 and no reflection, starting from `crc`
+///
+/// Despite the name, this is CRC-16/CCITT-FALSE when `crc` is `0xffff`, as used
+/// by EMVCo QR Codes. Only CRC-16/XMODEM when `crc` is `0`.
+#[deprecated(note = "use `Crc16::CCITT_FALSE` or `Crc16::new` instead")]
+pub fn crc16_xmodem(data: &str, crc: u16) -> u16 {
+    let engine = Crc16 {
+        init: crc,
+        ..Crc16::CCITT_FALSE
+    };
+    engine.checksum(data.as_bytes())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const CHECK: &[u8] = b"123456789";
+
     #[test]
+    #[allow(deprecated)]
     fn test_crc16_xmodem() {
         let data =
             "00020101021229370016A0000006770101110113006680111111153037645802TH540520.156304";
@@ -48,8 +188,30 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_crc16_xmodem_empty() {
         let result = crc16_xmodem("", 0xffff);
         assert_eq!(result, 0xffff);
     }
+
+    #[test]
+    fn test_crc16_presets() {
+        assert_eq!(Crc16::CCITT_FALSE.checksum(CHECK), 0x29b1);
+        assert_eq!(Crc16::XMODEM.checksum(CHECK), 0x31c3);
+        assert_eq!(Crc16::KERMIT.checksum(CHECK), 0x2189);
+        assert_eq!(Crc16::X25.checksum(CHECK), 0x906e);
+        assert_eq!(Crc16::ARC.checksum(CHECK), 0xbb3d);
+        assert_eq!(Crc16::MODBUS.checksum(CHECK), 0x4b37);
+    }
+
+    #[test]
+    fn test_crc16_streaming() {
+        let crc = Crc16::X25;
+        let mut digest = crc.digest();
+        for chunk in CHECK.chunks(2) {
+            digest.update(chunk);
+        }
+        assert_eq!(digest.finalize(), crc.checksum(CHECK));
+        assert_eq!(Crc16::default(), Crc16::CCITT_FALSE);
+    }
 }
@@ -0,0 +1,228 @@
+/// ISO 4217 currency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Currency {
+    /// Alphabetic code (e.g. `THB`)
+    pub alpha: &'static str,
+    /// Numeric code used in Tag 53 (e.g. `764`)
+    pub numeric: &'static str,
+    /// Number of decimal places of the minor unit
+    pub minor_units: u8,
+}
+
+const fn iso(alpha: &'static str, numeric: &'static str, minor_units: u8) -> Currency {
+    Currency {
+        alpha,
+        numeric,
+        minor_units,
+    }
+}
+
+/// Active ISO 4217 currencies
+pub const CURRENCIES: &[Currency] = &[
+    iso("AED", "784", 2),
+    iso("AFN", "971", 2),
+    iso("ALL", "008", 2),
+    iso("AMD", "051", 2),
+    iso("AOA", "973", 2),
+    iso("ARS", "032", 2),
+    iso("AUD", "036", 2),
+    iso("AWG", "533", 2),
+    iso("AZN", "944", 2),
+    iso("BAM", "977", 2),
+    iso("BBD", "052", 2),
+    iso("BDT", "050", 2),
+    iso("BGN", "975", 2),
+    iso("BHD", "048", 3),
+    iso("BIF", "108", 0),
+    iso("BMD", "060", 2),
+    iso("BND", "096", 2),
+    iso("BOB", "068", 2),
+    iso("BOV", "984", 2),
+    iso("BRL", "986", 2),
+    iso("BSD", "044", 2),
+    iso("BTN", "064", 2),
+    iso("BWP", "072", 2),
+    iso("BYN", "933", 2),
+    iso("BZD", "084", 2),
+    iso("CAD", "124", 2),
+    iso("CDF", "976", 2),
+    iso("CHE", "947", 2),
+    iso("CHF", "756", 2),
+    iso("CHW", "948", 2),
+    iso("CLF", "990", 4),
+    iso("CLP", "152", 0),
+    iso("CNY", "156", 2),
+    iso("COP", "170", 2),
+    iso("COU", "970", 2),
+    iso("CRC", "188", 2),
+    iso("CUP", "192", 2),
+    iso("CVE", "132", 2),
+    iso("CZK", "203", 2),
+    iso("DJF", "262", 0),
+    iso("DKK", "208", 2),
+    iso("DOP", "214", 2),
+    iso("DZD", "012", 2),
+    iso("EGP", "818", 2),
+    iso("ERN", "232", 2),
+    iso("ETB", "230", 2),
+    iso("EUR", "978", 2),
+    iso("FJD", "242", 2),
+    iso("FKP", "238", 2),
+    iso("GBP", "826", 2),
+    iso("GEL", "981", 2),
+    iso("GHS", "936", 2),
+    iso("GIP", "292", 2),
+    iso("GMD", "270", 2),
+    iso("GNF", "324", 0),
+    iso("GTQ", "320", 2),
+    iso("GYD", "328", 2),
+    iso("HKD", "344", 2),
+    iso("HNL", "340", 2),
+    iso("HTG", "332", 2),
+    iso("HUF", "348", 2),
+    iso("IDR", "360", 2),
+    iso("ILS", "376", 2),
+    iso("INR", "356", 2),
+    iso("IQD", "368", 3),
+    iso("IRR", "364", 2),
+    iso("ISK", "352", 0),
+    iso("JMD", "388", 2),
+    iso("JOD", "400", 3),
+    iso("JPY", "392", 0),
+    iso("KES", "404", 2),
+    iso("KGS", "417", 2),
+    iso("KHR", "116", 2),
+    iso("KMF", "174", 0),
+    iso("KPW", "408", 2),
+    iso("KRW", "410", 0),
+    iso("KWD", "414", 3),
+    iso("KYD", "136", 2),
+    iso("KZT", "398", 2),
+    iso("LAK", "418", 2),
+    iso("LBP", "422", 2),
+    iso("LKR", "144", 2),
+    iso("LRD", "430", 2),
+    iso("LSL", "426", 2),
+    iso("LYD", "434", 3),
+    iso("MAD", "504", 2),
+    iso("MDL", "498", 2),
+    iso("MGA", "969", 2),
+    iso("MKD", "807", 2),
+    iso("MMK", "104", 2),
+    iso("MNT", "496", 2),
+    iso("MOP", "446", 2),
+    iso("MRU", "929", 2),
+    iso("MUR", "480", 2),
+    iso("MVR", "462", 2),
+    iso("MWK", "454", 2),
+    iso("MXN", "484", 2),
+    iso("MXV", "979", 2),
+    iso("MYR", "458", 2),
+    iso("MZN", "943", 2),
+    iso("NAD", "516", 2),
+    iso("NGN", "566", 2),
+    iso("NIO", "558", 2),
+    iso("NOK", "578", 2),
+    iso("NPR", "524", 2),
+    iso("NZD", "554", 2),
+    iso("OMR", "512", 3),
+    iso("PAB", "590", 2),
+    iso("PEN", "604", 2),
+    iso("PGK", "598", 2),
+    iso("PHP", "608", 2),
+    iso("PKR", "586", 2),
+    iso("PLN", "985", 2),
+    iso("PYG", "600", 0),
+    iso("QAR", "634", 2),
+    iso("RON", "946", 2),
+    iso("RSD", "941", 2),
+    iso("RUB", "643", 2),
+    iso("RWF", "646", 0),
+    iso("SAR", "682", 2),
+    iso("SBD", "090", 2),
+    iso("SCR", "690", 2),
+    iso("SDG", "938", 2),
+    iso("SEK", "752", 2),
+    iso("SGD", "702", 2),
+    iso("SHP", "654", 2),
+    iso("SLE", "925", 2),
+    iso("SOS", "706", 2),
+    iso("SRD", "968", 2),
+    iso("SSP", "728", 2),
+    iso("STN", "930", 2),
+    iso("SVC", "222", 2),
+    iso("SYP", "760", 2),
+    iso("SZL", "748", 2),
+    iso("THB", "764", 2),
+    iso("TJS", "972", 2),
+    iso("TMT", "934", 2),
+    iso("TND", "788", 3),
+    iso("TOP", "776", 2),
+    iso("TRY", "949", 2),
+    iso("TTD", "780", 2),
+    iso("TWD", "901", 2),
+    iso("TZS", "834", 2),
+    iso("UAH", "980", 2),
+    iso("UGX", "800", 0),
+    iso("USD", "840", 2),
+    iso("USN", "997", 2),
+    iso("UYI", "940", 0),
+    iso("UYU", "858", 2),
+    iso("UYW", "927", 4),
+    iso("UZS", "860", 2),
+    iso("VED", "926", 2),
+    iso("VES", "928", 2),
+    iso("VND", "704", 0),
+    iso("VUV", "548", 0),
+    iso("WST", "882", 2),
+    iso("XAF", "950", 0),
+    iso("XCD", "951", 2),
+    iso("XCG", "532", 2),
+    iso("XOF", "952", 0),
+    iso("XPF", "953", 0),
+    iso("YER", "886", 2),
+    iso("ZAR", "710", 2),
+    iso("ZMW", "967", 2),
+    iso("ZWG", "924", 2),
+];
+
+/// Find ISO 4217 currency by its numeric or alphabetic code
+///
+/// # Arguments
+/// * `code` - Numeric (`764`) or alphabetic (`THB`) currency code
+///
+/// # Returns
+/// Currency or None if the code is not an active ISO 4217 currency
+pub fn find_currency(code: &str) -> Option<&'static Currency> {
+    CURRENCIES
+        .iter()
+        .find(|c| c.numeric == code || c.alpha.eq_ignore_ascii_case(code))
+}
+
+/// Number of decimal places used by a currency
+///
+/// Unknown currencies default to 2 decimal places
+pub fn currency_decimals(code: &str) -> u8 {
+    find_currency(code).map(|c| c.minor_units).unwrap_or(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_currency() {
+        let thb = find_currency("764").unwrap();
+        assert_eq!(thb.alpha, "THB");
+        assert_eq!(find_currency("thb"), Some(thb));
+        assert!(find_currency("999").is_none());
+    }
+
+    #[test]
+    fn test_currency_decimals() {
+        assert_eq!(currency_decimals("764"), 2);
+        assert_eq!(currency_decimals("704"), 0);
+        assert_eq!(currency_decimals("048"), 3);
+        assert_eq!(currency_decimals("000"), 2);
+    }
+}
@@ -0,0 +1,41 @@
+/// Gregorian leap year
+pub fn is_leap_year(year: u32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in a month (1-12)
+pub fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Check whether the date exists
+pub fn is_valid_date(year: u32, month: u32, day: u32) -> bool {
+    (1..=12).contains(&month) && day != 0 && day <= days_in_month(year, month)
+}
+
+/// Check whether a `YYYYMMDD` string is an existing date
+pub fn is_valid_yyyymmdd(value: &str) -> bool {
+    if value.len() != 8 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let number = |range: std::ops::Range<usize>| value[range].parse::<u32>().unwrap_or(0);
+    is_valid_date(number(0..4), number(4..6), number(6..8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_yyyymmdd() {
+        assert!(is_valid_yyyymmdd("20240229"));
+        assert!(!is_valid_yyyymmdd("20230229"));
+        assert!(!is_valid_yyyymmdd("20241301"));
+        assert!(!is_valid_yyyymmdd("2024-1-1"));
+    }
+}
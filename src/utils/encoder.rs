@@ -16,6 +16,29 @@ pub fn encode_tag81(message: &str) -> String {
         .join("")
 }
 
+/// Decode a UCS-2-like Hex string from Tag 81
+///
+/// This is the reverse of [`encode_tag81`]
+///
+/// # Arguments
+/// * `hex` - Hex string from Tag 81
+///
+/// # Returns
+/// Decoded message or None if the string is not valid UCS-2 hex
+pub fn decode_tag81(hex: &str) -> Option<String> {
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    hex.as_bytes()
+        .chunks(4)
+        .map(|chunk| {
+            let code = std::str::from_utf8(chunk).ok().filter(|c| c.len() == 4)?;
+            u32::from_str_radix(code, 16).ok().and_then(char::from_u32)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +63,17 @@ mod tests {
         let result = encode_tag81(message);
         assert_eq!(result, "00E500E400F6");
     }
+
+    #[test]
+    fn test_decode_tag81() {
+        let hex = "00480065006C006C006F00200057006F0072006C00640021";
+        assert_eq!(decode_tag81(hex), Some("Hello World!".to_string()));
+        assert_eq!(decode_tag81(&encode_tag81("åäö")), Some("åäö".to_string()));
+    }
+
+    #[test]
+    fn test_decode_tag81_invalid() {
+        assert_eq!(decode_tag81("004"), None);
+        assert_eq!(decode_tag81("ZZZZ"), None);
+    }
 }
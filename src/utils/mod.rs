@@ -1,2 +1,5 @@
 pub mod checksum;
+pub mod country;
+pub mod currency;
+pub mod date;
 pub mod encoder;
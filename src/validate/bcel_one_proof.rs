@@ -1,6 +1,9 @@
-use crate::parser::parse;
+use crate::error::PromptParseError;
+use crate::parser::try_parse;
+use crate::Result;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcelOneProofData {
     pub r#type: Option<String>,
     pub ticket: Option<String>,
@@ -15,25 +18,43 @@ pub struct BcelOneProofData {
 /// # Returns
 /// Type, Ticket No. and Reference No. or None if payload invalid
 pub fn bcel_one_proof(payload: &str) -> Option<BcelOneProofData> {
-    let ppqr = parse(payload, true, true)?;
+    try_bcel_one_proof(payload).ok()
+}
+
+/// Same as [`bcel_one_proof`] but reports why the payload was rejected
+pub fn try_bcel_one_proof(payload: &str) -> Result<BcelOneProofData> {
+    let ppqr = try_parse(payload, true, true)?;
 
     let r#type = ppqr.get_tag_value("33", Some("02")).map(|s| s.to_string());
     let ticket = ppqr.get_tag_value("33", Some("03")).map(|s| s.to_string());
     let fccref = ppqr.get_tag_value("33", Some("04")).map(|s| s.to_string());
 
-    let tag_00 = ppqr.get_tag_value("00", None)?;
-    let tag_01 = ppqr.get_tag_value("01", None)?;
-    let tag_33_00 = ppqr.get_tag_value("33", Some("00"))?;
+    let tag_00 = ppqr.try_get_tag_value("00", None)?;
+    let tag_01 = ppqr.try_get_tag_value("01", None)?;
+    let tag_33_00 = ppqr.try_get_tag_value("33", Some("00"))?;
 
-    if tag_00 != "01" || tag_01 != "11" {
-        return None;
+    if tag_00 != "01" {
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: "00".to_string(),
+            rule: "payload format indicator must be 01".to_string(),
+        });
+    }
+
+    if tag_01 != "11" {
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: "01".to_string(),
+            rule: "point of initiation must be 11".to_string(),
+        });
     }
 
     if tag_33_00 != "BCEL" && tag_33_00 != "ONEPROOF" {
-        return None;
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: "33.00".to_string(),
+            rule: "application ID must be BCEL or ONEPROOF".to_string(),
+        });
     }
 
-    Some(BcelOneProofData {
+    Ok(BcelOneProofData {
         r#type,
         ticket,
         fccref,
@@ -88,4 +109,17 @@ mod tests {
         let result = bcel_one_proof(payload);
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_try_bcel_one_proof_missing_tag_33() {
+        let payload = "00020101021229370016A0000006770101110113006680111111153037645802TH63041DCF";
+        assert!(try_bcel_one_proof(payload).is_err());
+
+        let payload =
+            "00020101021229370016A0000006770101110113006680111111153037645802TH540520.15630442BE";
+        assert!(matches!(
+            try_bcel_one_proof(payload),
+            Err(PromptParseError::TagNotFound(path)) if path == "33.00"
+        ));
+    }
 }
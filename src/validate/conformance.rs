@@ -0,0 +1,465 @@
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::spec::{self, check_value, data_object, is_merchant_account, parse_id, Format};
+use crate::tlv::{checksum, decode, TlvTag};
+use crate::utils::country::is_country_code;
+use crate::utils::currency::find_currency;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Application ID prefix of PromptPay merchant account templates
+const PROMPTPAY_AID_PREFIX: &str = "A000000677";
+
+/// Severity of a conformance finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Allowed by the specification but unusual
+    Info,
+    /// Likely to be rejected by some scanners
+    Warning,
+    /// Violates the EMVCo specification
+    Error,
+}
+
+/// A single specification violation in a QR payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    /// Tag path, e.g. `54` or `62.05`
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} [{}] {}", self.severity, self.path, self.message)
+    }
+}
+
+/// Check a parsed QR against the EMVCo Merchant-Presented Mode specification
+///
+/// # Arguments
+/// * `qr` - Parsed QR Code (with or without sub-tags)
+///
+/// # Returns
+/// Every violation found, empty if the payload conforms
+pub fn conformance(qr: &EmvCoQr) -> Vec<Finding> {
+    let mut lint = Lint::default();
+    let tags = qr.get_tags();
+
+    lint.check_structure(qr);
+    lint.check_tags(tags);
+
+    let value = |id: &str| tags.iter().find(|t| t.id == id).map(|t| t.value.as_str());
+    let is_promptpay = tags
+        .iter()
+        .filter(|t| t.id == "29" || t.id == "30")
+        .filter_map(template_tags)
+        .any(|sub| {
+            sub.iter()
+                .any(|s| s.id == "00" && s.value.starts_with(PROMPTPAY_AID_PREFIX))
+        });
+
+    if !tags.iter().any(|t| is_merchant_account(&t.id)) {
+        lint.error(
+            "02-51",
+            "at least one Merchant Account Information is required",
+        );
+    }
+
+    let mut mandatory = vec![spec::TRANSACTION_CURRENCY, spec::COUNTRY_CODE];
+    if !is_promptpay {
+        mandatory.extend([
+            spec::MERCHANT_CATEGORY_CODE,
+            spec::MERCHANT_NAME,
+            spec::MERCHANT_CITY,
+        ]);
+    }
+    for id in mandatory {
+        if value(id).is_none() {
+            let name = data_object(id).map_or("", |o| o.name);
+            lint.error(id, &format!("{name} is required"));
+        }
+    }
+
+    let currency = value(spec::TRANSACTION_CURRENCY).and_then(|code| {
+        let currency = find_currency(code).filter(|c| c.numeric == code);
+        if currency.is_none() {
+            lint.error(
+                spec::TRANSACTION_CURRENCY,
+                &format!("{code} is not an ISO 4217 numeric currency code"),
+            );
+        }
+        currency
+    });
+
+    if let Some(country) = value(spec::COUNTRY_CODE) {
+        if !is_country_code(country) {
+            lint.error(
+                spec::COUNTRY_CODE,
+                &format!("{country} is not an ISO 3166-1 alpha-2 country code"),
+            );
+        }
+    }
+
+    let amount = value(spec::TRANSACTION_AMOUNT).and_then(|value| {
+        let amount = lint.check_amount(spec::TRANSACTION_AMOUNT, value);
+        if let (Some(amount), Some(currency)) = (amount, currency) {
+            if amount.decimals() > currency.minor_units {
+                lint.warning(
+                    spec::TRANSACTION_AMOUNT,
+                    &format!(
+                        "{} uses {} decimal places, amount has {}",
+                        currency.alpha,
+                        currency.minor_units,
+                        amount.decimals()
+                    ),
+                );
+            }
+            if amount.is_zero() {
+                lint.warning(spec::TRANSACTION_AMOUNT, "amount is zero");
+            }
+        }
+        amount
+    });
+
+    match (value(spec::POINT_OF_INITIATION), amount) {
+        (Some("11"), Some(_)) => lint.warning(
+            spec::POINT_OF_INITIATION,
+            "static QR (11) should not contain a Transaction Amount",
+        ),
+        (Some("12"), None) => lint.info(
+            spec::POINT_OF_INITIATION,
+            "dynamic QR (12) without a Transaction Amount",
+        ),
+        (Some("11" | "12") | None, _) => {}
+        (Some(other), _) => lint.error(
+            spec::POINT_OF_INITIATION,
+            &format!("Point of Initiation Method must be 11 or 12, got {other}"),
+        ),
+    }
+
+    lint.check_tip(
+        value(spec::TIP_OR_CONVENIENCE_INDICATOR),
+        value(spec::CONVENIENCE_FEE_FIXED),
+        value(spec::CONVENIENCE_FEE_PERCENTAGE),
+    );
+
+    lint.findings
+}
+
+#[derive(Default)]
+struct Lint {
+    findings: Vec<Finding>,
+}
+
+impl Lint {
+    fn push(&mut self, severity: Severity, path: &str, message: &str) {
+        self.findings.push(Finding {
+            severity,
+            path: path.to_string(),
+            message: message.to_string(),
+        });
+    }
+
+    fn error(&mut self, path: &str, message: &str) {
+        self.push(Severity::Error, path, message);
+    }
+
+    fn warning(&mut self, path: &str, message: &str) {
+        self.push(Severity::Warning, path, message);
+    }
+
+    fn info(&mut self, path: &str, message: &str) {
+        self.push(Severity::Info, path, message);
+    }
+
+    fn check_structure(&mut self, qr: &EmvCoQr) {
+        let tags = qr.get_tags();
+
+        match tags.first() {
+            Some(first) if first.id == spec::PAYLOAD_FORMAT_INDICATOR => {
+                if first.value != "01" {
+                    self.error(
+                        spec::PAYLOAD_FORMAT_INDICATOR,
+                        "Payload Format Indicator must be 01",
+                    );
+                }
+            }
+            _ => self.error(
+                spec::PAYLOAD_FORMAT_INDICATOR,
+                "Payload Format Indicator must be the first tag",
+            ),
+        }
+
+        match tags.last() {
+            Some(last) if last.id == spec::CRC => {
+                let payload = qr.get_payload();
+                let crc_start = payload.len().saturating_sub(4);
+                if payload.is_char_boundary(crc_start) {
+                    let expected = checksum(&payload[..crc_start], true);
+                    if !last.value.eq_ignore_ascii_case(&expected) {
+                        self.error(
+                            spec::CRC,
+                            &format!("CRC {} does not match expected {expected}", last.value),
+                        );
+                    }
+                }
+            }
+            _ => self.error(spec::CRC, "CRC must be the last tag"),
+        }
+    }
+
+    fn check_tags(&mut self, tags: &[TlvTag]) {
+        let mut seen = HashSet::new();
+        let mut previous: Option<u8> = None;
+
+        for tag in tags {
+            if !seen.insert(tag.id.as_str()) {
+                self.error(&tag.id, &format!("duplicate tag {}", tag.id));
+            }
+
+            if let Some(rule) = check_value(&tag.id, &tag.value) {
+                self.error(&tag.id, &rule);
+            }
+
+            if let Some(id) = parse_id(&tag.id) {
+                if let Some(prev) = previous {
+                    if id < prev && tag.id != spec::CRC {
+                        self.warning(
+                            &tag.id,
+                            &format!("tag {} appears after tag {prev:02}", tag.id),
+                        );
+                    }
+                }
+                previous = Some(id);
+            }
+
+            if data_object(&tag.id).map(|o| o.format) == Some(Format::Template) {
+                self.check_template(tag);
+            }
+        }
+    }
+
+    fn check_template(&mut self, tag: &TlvTag) {
+        let Some(sub_tags) = template_tags(tag) else {
+            self.error(&tag.id, "template value is not valid TLV data");
+            return;
+        };
+
+        let mut seen = HashSet::new();
+        for sub_tag in &sub_tags {
+            let path = format!("{}.{}", tag.id, sub_tag.id);
+            if parse_id(&sub_tag.id).is_none() {
+                self.error(&path, "tag ID must be a two-digit number");
+            }
+            if !seen.insert(sub_tag.id.as_str()) {
+                self.error(&path, &format!("duplicate sub-tag {}", sub_tag.id));
+            }
+            if sub_tag.value.is_empty() {
+                self.error(&path, "value must not be empty");
+            }
+        }
+
+        let has = |id: &str| seen.contains(id);
+        if is_merchant_account(&tag.id) && !has("00") {
+            self.error(
+                &format!("{}.00", tag.id),
+                "Globally Unique Identifier is required",
+            );
+        }
+        if tag.id == spec::MERCHANT_INFORMATION_LANGUAGE {
+            for (id, name) in [("00", "Language Preference"), ("01", "Merchant Name")] {
+                if !has(id) {
+                    self.error(&format!("{}.{id}", tag.id), &format!("{name} is required"));
+                }
+            }
+        }
+    }
+
+    fn check_amount(&mut self, path: &str, value: &str) -> Option<Amount> {
+        let amount = value.parse::<Amount>().ok();
+        if amount.is_none() {
+            self.error(
+                path,
+                &format!("{value} is not digits with an optional decimal point"),
+            );
+        }
+        amount
+    }
+
+    fn check_tip(
+        &mut self,
+        indicator: Option<&str>,
+        fixed: Option<&str>,
+        percentage: Option<&str>,
+    ) {
+        if let Some(fixed) = fixed {
+            self.check_amount(spec::CONVENIENCE_FEE_FIXED, fixed);
+            if indicator != Some("02") {
+                self.error(
+                    spec::CONVENIENCE_FEE_FIXED,
+                    "Convenience Fee Fixed requires Tip or Convenience Indicator 02",
+                );
+            }
+        }
+
+        if let Some(percentage) = percentage {
+            if let Some(value) = self.check_amount(spec::CONVENIENCE_FEE_PERCENTAGE, percentage) {
+                if value > Amount::from_minor_units(100, 0).unwrap_or(value) {
+                    self.warning(
+                        spec::CONVENIENCE_FEE_PERCENTAGE,
+                        "percentage is greater than 100",
+                    );
+                }
+            }
+            if indicator != Some("03") {
+                self.error(
+                    spec::CONVENIENCE_FEE_PERCENTAGE,
+                    "Convenience Fee Percentage requires Tip or Convenience Indicator 03",
+                );
+            }
+        }
+
+        match indicator {
+            None | Some("01") => {}
+            Some("02") if fixed.is_none() => self.error(
+                spec::CONVENIENCE_FEE_FIXED,
+                "Tip or Convenience Indicator 02 requires Convenience Fee Fixed",
+            ),
+            Some("03") if percentage.is_none() => self.error(
+                spec::CONVENIENCE_FEE_PERCENTAGE,
+                "Tip or Convenience Indicator 03 requires Convenience Fee Percentage",
+            ),
+            Some("02" | "03") => {}
+            Some(other) => self.error(
+                spec::TIP_OR_CONVENIENCE_INDICATOR,
+                &format!("Tip or Convenience Indicator must be 01, 02 or 03, got {other}"),
+            ),
+        }
+    }
+}
+
+/// Get sub-tags of a template, decoding the value if it was parsed without sub-tags
+fn template_tags(tag: &TlvTag) -> Option<Vec<TlvTag>> {
+    match &tag.sub_tags {
+        Some(sub_tags) => Some(sub_tags.clone()),
+        None => decode(&tag.value).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::MerchantQrBuilder;
+    use crate::parser::parse;
+    use crate::tlv::{encode, tag, with_crc_tag};
+
+    fn lint(tags: &[TlvTag]) -> Vec<Finding> {
+        let payload = with_crc_tag(&encode(tags), "63", true);
+        conformance(&parse(&payload, false, true).unwrap())
+    }
+
+    fn has(findings: &[Finding], severity: Severity, path: &str) -> bool {
+        findings
+            .iter()
+            .any(|f| f.severity == severity && f.path == path)
+    }
+
+    #[test]
+    fn test_conformance_promptpay_clean() {
+        let payload =
+            "00020101021229370016A0000006770101110113006680111111153037645802TH540520.15630442BE";
+        let findings = conformance(&parse(payload, true, true).unwrap());
+        // PromptPay puts Tag 54 after Tag 58
+        assert_eq!(findings.len(), 1);
+        assert!(has(&findings, Severity::Warning, "54"));
+    }
+
+    #[test]
+    fn test_conformance_builder_clean() {
+        let payload = MerchantQrBuilder::new()
+            .merchant_account("26", vec![tag("00", "SG.PAYNOW"), tag("01", "2")])
+            .merchant_category_code("0000")
+            .currency("702")
+            .country("SG")
+            .merchant_name("NETS")
+            .merchant_city("Singapore")
+            .build()
+            .unwrap();
+        assert!(conformance(&parse(&payload, true, false).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_conformance_structure() {
+        let findings = conformance(&parse("01021100020163041234", false, true).unwrap());
+        assert!(has(&findings, Severity::Error, "00"));
+        assert!(has(&findings, Severity::Error, "63"));
+        assert!(has(&findings, Severity::Error, "02-51"));
+        assert!(has(&findings, Severity::Error, "53"));
+
+        let findings = conformance(&parse("000201530376453037646304ABCD", false, true).unwrap());
+        assert!(has(&findings, Severity::Error, "53"));
+        assert!(findings.iter().any(|f| f.message.contains("duplicate")));
+    }
+
+    #[test]
+    fn test_conformance_field_values() {
+        let findings = lint(&[
+            tag("00", "01"),
+            tag("01", "11"),
+            tag("26", "0009SG.PAYNOW"),
+            tag("52", "59X9"),
+            tag("53", "999"),
+            tag("54", "1,000"),
+            tag("58", "XX"),
+            tag("59", "NETS"),
+        ]);
+        assert!(has(&findings, Severity::Error, "52"));
+        assert!(has(&findings, Severity::Error, "53"));
+        assert!(has(&findings, Severity::Error, "54"));
+        assert!(has(&findings, Severity::Error, "58"));
+        assert!(has(&findings, Severity::Error, "60"));
+    }
+
+    #[test]
+    fn test_conformance_point_of_initiation_and_tip() {
+        let findings = lint(&[
+            tag("00", "01"),
+            tag("01", "11"),
+            tag("29", "0016A00000067701011101130066812223333"),
+            tag("53", "764"),
+            tag("54", "10.00"),
+            tag("55", "01"),
+            tag("56", "5.00"),
+            tag("58", "TH"),
+        ]);
+        assert!(has(&findings, Severity::Warning, "01"));
+        assert!(has(&findings, Severity::Error, "56"));
+
+        let findings = lint(&[
+            tag("00", "01"),
+            tag("01", "12"),
+            tag("29", "0016A00000067701011101130066812223333"),
+            tag("53", "764"),
+            tag("55", "03"),
+            tag("58", "TH"),
+        ]);
+        assert!(has(&findings, Severity::Info, "01"));
+        assert!(has(&findings, Severity::Error, "57"));
+    }
+
+    #[test]
+    fn test_conformance_templates() {
+        let findings = lint(&[
+            tag("00", "01"),
+            tag("29", "011300668122233330100"),
+            tag("53", "764"),
+            tag("58", "TH"),
+            tag("64", "0002TH"),
+        ]);
+        assert!(has(&findings, Severity::Error, "29.00"));
+        assert!(has(&findings, Severity::Error, "29.01"));
+        assert!(has(&findings, Severity::Error, "64.01"));
+    }
+}
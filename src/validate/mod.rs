@@ -1,7 +1,13 @@
 pub mod bcel_one_proof;
+pub mod conformance;
 pub mod slip_verify;
+pub mod trans_ref;
 pub mod true_money_slip_verify;
 
-pub use bcel_one_proof::{bcel_one_proof, BcelOneProofData};
-pub use slip_verify::{slip_verify, SlipVerifyData};
-pub use true_money_slip_verify::{true_money_slip_verify, TrueMoneySlipVerifyData};
+pub use bcel_one_proof::{bcel_one_proof, try_bcel_one_proof, BcelOneProofData};
+pub use conformance::{conformance, Finding, Severity};
+pub use slip_verify::{slip_verify, try_slip_verify, try_slip_verify_repaired, SlipVerifyData};
+pub use trans_ref::{TransRefDecoder, TransRefDecoders, TransRefFields, TransRefInfo};
+pub use true_money_slip_verify::{
+    true_money_slip_verify, try_true_money_slip_verify, TrueMoneySlipVerifyData,
+};
@@ -1,11 +1,36 @@
-use crate::parser::parse;
+use crate::bank::Bank;
+use crate::error::PromptParseError;
+use crate::parser::try_parse_with;
+use crate::repair::{Repair, RepairPolicy, Repaired};
+use crate::schema::TagSchema;
+use crate::validate::trans_ref::{TransRefDecoders, TransRefInfo};
+use crate::Result;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlipVerifyData {
     pub sending_bank: String,
     pub trans_ref: String,
 }
 
+impl SlipVerifyData {
+    /// Sending bank looked up in the bank directory
+    pub fn bank(&self) -> Bank {
+        Bank::from_code(&self.sending_bank)
+    }
+
+    /// Date, time, sequence and other fields encoded in the transaction
+    /// reference, using the built-in rules for the sending bank
+    pub fn trans_ref_info(&self) -> TransRefInfo {
+        self.trans_ref_info_with(TransRefDecoders::builtin())
+    }
+
+    /// Same as [`SlipVerifyData::trans_ref_info`] with a custom set of decoders
+    pub fn trans_ref_info_with(&self, decoders: &TransRefDecoders) -> TransRefInfo {
+        decoders.decode(&self.bank(), &self.trans_ref)
+    }
+}
+
 /// Validate & extract data from Slip Verify QR (for use with Bank Open API)
 ///
 /// # Arguments
@@ -15,34 +40,56 @@ pub struct SlipVerifyData {
 /// # Returns
 /// Bank code and Transaction reference or None if payload invalid
 pub fn slip_verify(payload: &str, crc_auto_fix: bool) -> Option<SlipVerifyData> {
-    let mut payload = payload.to_string();
-
-    if crc_auto_fix {
-        if let Some(idx) = payload.rfind("9104") {
-            let crc_start = idx + 4;
-            if crc_start < payload.len() {
-                let crc = &payload[crc_start..];
-                if !crc.is_empty() && crc.len() < 4 {
-                    // Pad the CRC with leading zeros to make it 4 characters
-                    payload = format!("{}{:0>4}", &payload[..crc_start], crc);
-                }
-            }
-        }
-    }
+    try_slip_verify(payload, crc_auto_fix).ok()
+}
+
+/// Same as [`slip_verify`] but reports why the payload was rejected
+///
+/// `crc_auto_fix` is the same as allowing [`Repair::PadCrc`]
+pub fn try_slip_verify(payload: &str, crc_auto_fix: bool) -> Result<SlipVerifyData> {
+    let policy = if crc_auto_fix {
+        RepairPolicy::new().allow(Repair::PadCrc)
+    } else {
+        RepairPolicy::new()
+    };
+
+    try_slip_verify_repaired(payload, &policy).map(|repaired| repaired.value)
+}
+
+/// Validate & extract data from a damaged Slip Verify QR after applying the
+/// repairs allowed by `policy`
+///
+/// # Returns
+/// Bank code and Transaction reference with the repairs that were applied,
+/// or error describing why the repaired payload was rejected
+pub fn try_slip_verify_repaired(
+    payload: &str,
+    policy: &RepairPolicy,
+) -> Result<Repaired<SlipVerifyData>> {
+    let Repaired {
+        value: payload,
+        repairs,
+    } = policy.repair(payload);
 
-    let ppqr = parse(&payload, true, true)?;
+    let ppqr = try_parse_with(&payload, true, TagSchema::slip_verify())?;
 
-    let api_type = ppqr.get_tag_value("00", Some("00"))?;
+    let api_type = ppqr.try_get_tag_value("00", Some("00"))?;
     if api_type != "000001" {
-        return None;
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: "00.00".to_string(),
+            rule: format!("API type must be 000001, got {api_type}"),
+        });
     }
 
-    let sending_bank = ppqr.get_tag_value("00", Some("01"))?;
-    let trans_ref = ppqr.get_tag_value("00", Some("02"))?;
+    let sending_bank = ppqr.try_get_tag_value("00", Some("01"))?;
+    let trans_ref = ppqr.try_get_tag_value("00", Some("02"))?;
 
-    Some(SlipVerifyData {
-        sending_bank: sending_bank.to_string(),
-        trans_ref: trans_ref.to_string(),
+    Ok(Repaired {
+        value: SlipVerifyData {
+            sending_bank: sending_bank.to_string(),
+            trans_ref: trans_ref.to_string(),
+        },
+        repairs,
     })
 }
 
@@ -55,6 +102,8 @@ mod tests {
         let payload = "004100060000010103014022000111222233344ABCD126304BA3C";
         let result = slip_verify(payload, true).unwrap();
         assert_eq!(result.sending_bank, "014");
+        assert_eq!(result.bank(), Bank::Scb);
+        assert_eq!(result.trans_ref_info(), TransRefInfo::Unknown);
         assert_eq!(result.trans_ref, "00111222233344ABCD12");
     }
 
@@ -71,4 +120,37 @@ mod tests {
         let payload = "004000060000020103014022000111222233344ABCD125102TH9104XXXX";
         assert!(slip_verify(payload, true).is_none());
     }
+
+    #[test]
+    fn test_try_slip_verify_errors() {
+        let payload =
+            "00020101021229370016A0000006770101110113006680111111153037645802TH540520.15630442BE";
+        assert!(matches!(
+            try_slip_verify(payload, true),
+            Err(PromptParseError::TagNotFound(path)) if path == "00.00"
+        ));
+
+        let payload = "004000060000020103014022000111222233344ABCD125102TH9104XXXX";
+        assert!(matches!(
+            try_slip_verify(payload, true),
+            Err(PromptParseError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_slip_verify_repaired() {
+        let payload = "\u{200b}004100060000010103014022000111222233344ABCD125102TH910417df\r\n";
+        assert!(slip_verify(payload, true).is_none());
+
+        let repaired = try_slip_verify_repaired(payload, &RepairPolicy::all()).unwrap();
+        assert_eq!(repaired.value.trans_ref, "00111222233344ABCD12");
+        assert_eq!(
+            repaired.repairs,
+            vec![
+                Repair::StripInvisible,
+                Repair::TrimWhitespace,
+                Repair::UppercaseCrc
+            ]
+        );
+    }
 }
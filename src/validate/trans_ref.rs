@@ -0,0 +1,260 @@
+use crate::bank::Bank;
+use crate::utils::date::{days_in_month, is_valid_date};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Structured fields found in a Slip Verify transaction reference
+///
+/// Decoders fill in only what the bank's format carries. Banks do not publish
+/// their reference formats, so the built-in rules are heuristics.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransRefFields {
+    /// Transaction date (`YYYY-MM-DD`)
+    pub date: Option<String>,
+    /// Transaction time (`HH:MM:SS`)
+    pub time: Option<String>,
+    /// Channel the transaction was made from
+    pub channel: Option<String>,
+    /// Sequence or running number
+    pub sequence: Option<String>,
+    /// Branch code
+    ///
+    /// None of the built-in decoders fill this in, as neither KBANK nor SCB
+    /// references are known to carry a branch; custom decoders may
+    pub branch: Option<String>,
+}
+
+/// Result of decoding a transaction reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum TransRefInfo {
+    /// Reference matched the bank's format
+    Decoded(TransRefFields),
+    /// No rule for the bank, or the reference does not match its format
+    Unknown,
+}
+
+/// Per-bank transaction reference format
+pub trait TransRefDecoder: Send + Sync {
+    /// Extract the known fields, or None if the reference does not match
+    fn decode(&self, trans_ref: &str) -> Option<TransRefFields>;
+}
+
+impl<F> TransRefDecoder for F
+where
+    F: Fn(&str) -> Option<TransRefFields> + Send + Sync,
+{
+    fn decode(&self, trans_ref: &str) -> Option<TransRefFields> {
+        self(trans_ref)
+    }
+}
+
+/// Registry of transaction reference decoders by sending bank
+#[derive(Default)]
+pub struct TransRefDecoders {
+    decoders: HashMap<Bank, Box<dyn TransRefDecoder>>,
+}
+
+impl TransRefDecoders {
+    /// Empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the built-in rules for KBANK and SCB
+    ///
+    /// These formats are not published by the banks. They are inferred from
+    /// references printed on real slips and may not cover every channel, so a
+    /// reference they do not match is reported as [`TransRefInfo::Unknown`].
+    pub fn builtin() -> &'static TransRefDecoders {
+        static BUILTIN: OnceLock<TransRefDecoders> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            TransRefDecoders::new()
+                .register(Bank::Kbank, kbank)
+                .register(Bank::Scb, scb)
+        })
+    }
+
+    /// Use `decoder` for references from `bank`, replacing any existing rule
+    pub fn register<D: TransRefDecoder + 'static>(mut self, bank: Bank, decoder: D) -> Self {
+        self.decoders.insert(bank, Box::new(decoder));
+        self
+    }
+
+    /// Decode a reference sent by `bank`
+    pub fn decode(&self, bank: &Bank, trans_ref: &str) -> TransRefInfo {
+        self.decoders
+            .get(bank)
+            .and_then(|decoder| decoder.decode(trans_ref))
+            .map_or(TransRefInfo::Unknown, TransRefInfo::Decoded)
+    }
+}
+
+impl std::fmt::Debug for TransRefDecoders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransRefDecoders")
+            .field("banks", &self.decoders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+fn digits(value: &str) -> Option<u32> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// `YYYY-MM-DD` if the date exists
+fn format_date(year: u32, month: u32, day: u32) -> Option<String> {
+    if !is_valid_date(year, month, day) {
+        return None;
+    }
+    Some(format!("{year:04}-{month:02}-{day:02}"))
+}
+
+/// `YYYY-MM-DD` from a day of the year (1-366)
+fn ordinal_date(year: u32, mut ordinal: u32) -> Option<String> {
+    for month in 1..=12 {
+        let days = days_in_month(year, month);
+        if ordinal <= days {
+            return format_date(year, month, ordinal);
+        }
+        ordinal = ordinal.checked_sub(days)?;
+    }
+    None
+}
+
+/// `HH:MM:SS` from `HHMMSS`
+fn format_time(value: &str) -> Option<String> {
+    let (hour, minute, second) = (
+        digits(value.get(0..2)?)?,
+        digits(value.get(2..4)?)?,
+        digits(value.get(4..6)?)?,
+    );
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some(format!("{hour:02}:{minute:02}:{second:02}"))
+}
+
+/// KBANK: `0` + `YY` + day of year `DDD` + `HHMMSS` + 3-letter channel + 5-digit sequence,
+/// e.g. `015283143552BTF06978`
+///
+/// Heuristic: inferred from K PLUS slips, not a published KBANK format
+fn kbank(trans_ref: &str) -> Option<TransRefFields> {
+    if trans_ref.len() != 20 || !trans_ref.is_ascii() {
+        return None;
+    }
+
+    let year = 2000 + digits(&trans_ref[1..3])?;
+    let channel = &trans_ref[12..15];
+    let sequence = &trans_ref[15..20];
+    if !trans_ref.starts_with('0')
+        || !channel.bytes().all(|b| b.is_ascii_uppercase())
+        || digits(sequence).is_none()
+    {
+        return None;
+    }
+
+    Some(TransRefFields {
+        date: Some(ordinal_date(year, digits(&trans_ref[3..6])?)?),
+        time: Some(format_time(&trans_ref[6..12])?),
+        channel: Some(channel.to_string()),
+        sequence: Some(sequence.to_string()),
+        branch: None,
+    })
+}
+
+/// SCB: `YYYYMMDD` followed by an opaque identifier, e.g. `2023082421jUqvqUhtXGVJnCX`
+///
+/// Heuristic: inferred from SCB EASY slips, not a published SCB format. Only the
+/// date is read, the rest of the reference has no known structure
+fn scb(trans_ref: &str) -> Option<TransRefFields> {
+    if trans_ref.len() <= 8 || !trans_ref.is_ascii() {
+        return None;
+    }
+
+    let (year, month, day) = (
+        digits(&trans_ref[0..4])?,
+        digits(&trans_ref[4..6])?,
+        digits(&trans_ref[6..8])?,
+    );
+    if !(2000..=2099).contains(&year) {
+        return None;
+    }
+
+    Some(TransRefFields {
+        date: Some(format_date(year, month, day)?),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trans_ref_kbank() {
+        let info = TransRefDecoders::builtin().decode(&Bank::Kbank, "015283143552BTF06978");
+        assert_eq!(
+            info,
+            TransRefInfo::Decoded(TransRefFields {
+                date: Some("2015-10-10".to_string()),
+                time: Some("14:35:52".to_string()),
+                channel: Some("BTF".to_string()),
+                sequence: Some("06978".to_string()),
+                branch: None,
+            })
+        );
+
+        // Day 367 and 25 o'clock do not exist
+        let decoders = TransRefDecoders::builtin();
+        assert_eq!(
+            decoders.decode(&Bank::Kbank, "015367143552BTF06978"),
+            TransRefInfo::Unknown
+        );
+        assert_eq!(
+            decoders.decode(&Bank::Kbank, "015283253552BTF06978"),
+            TransRefInfo::Unknown
+        );
+    }
+
+    #[test]
+    fn test_trans_ref_scb() {
+        let decoders = TransRefDecoders::builtin();
+        match decoders.decode(&Bank::Scb, "2024022921jUqvqUhtXGVJnCX") {
+            TransRefInfo::Decoded(fields) => {
+                assert_eq!(fields.date.as_deref(), Some("2024-02-29"));
+                assert_eq!(fields.sequence, None);
+                assert_eq!(fields.branch, None);
+            }
+            TransRefInfo::Unknown => panic!("expected decoded SCB reference"),
+        }
+        assert_eq!(
+            decoders.decode(&Bank::Scb, "2023022921jUqvqUhtXGVJnCX"),
+            TransRefInfo::Unknown
+        );
+    }
+
+    #[test]
+    fn test_trans_ref_custom_decoder() {
+        let decoders = TransRefDecoders::new().register(Bank::Bbl, |trans_ref: &str| {
+            Some(TransRefFields {
+                branch: Some(trans_ref.get(0..4)?.to_string()),
+                ..Default::default()
+            })
+        });
+
+        match decoders.decode(&Bank::Bbl, "0002123123121200011") {
+            TransRefInfo::Decoded(fields) => assert_eq!(fields.branch.as_deref(), Some("0002")),
+            TransRefInfo::Unknown => panic!("expected decoded BBL reference"),
+        }
+        assert_eq!(
+            decoders.decode(&Bank::Kbank, "015283143552BTF06978"),
+            TransRefInfo::Unknown
+        );
+    }
+}
@@ -1,6 +1,10 @@
-use crate::parser::parse;
+use crate::error::PromptParseError;
+use crate::parser::try_parse_with;
+use crate::schema::TagSchema;
+use crate::Result;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrueMoneySlipVerifyData {
     pub event_type: String,
     pub transaction_id: String,
@@ -15,20 +19,28 @@ pub struct TrueMoneySlipVerifyData {
 /// # Returns
 /// Type, Transaction ID and Date (DDMMYYYY) or None if payload invalid
 pub fn true_money_slip_verify(payload: &str) -> Option<TrueMoneySlipVerifyData> {
-    let ppqr = parse(payload, true, true)?;
+    try_true_money_slip_verify(payload).ok()
+}
+
+/// Same as [`true_money_slip_verify`] but reports why the payload was rejected
+pub fn try_true_money_slip_verify(payload: &str) -> Result<TrueMoneySlipVerifyData> {
+    let ppqr = try_parse_with(payload, true, TagSchema::slip_verify())?;
 
-    let tag00_val = ppqr.get_tag_value("00", Some("00"))?;
-    let tag01_val = ppqr.get_tag_value("00", Some("01"))?;
+    let tag00_val = ppqr.try_get_tag_value("00", Some("00"))?;
+    let tag01_val = ppqr.try_get_tag_value("00", Some("01"))?;
 
     if tag00_val != "01" || tag01_val != "01" {
-        return None;
+        return Err(PromptParseError::InvalidTagValue {
+            tag_id: if tag00_val != "01" { "00.00" } else { "00.01" }.to_string(),
+            rule: "TrueMoney slip must have sub-tags 00 and 01 set to 01".to_string(),
+        });
     }
 
-    let event_type = ppqr.get_tag_value("00", Some("02"))?;
-    let transaction_id = ppqr.get_tag_value("00", Some("03"))?;
-    let date = ppqr.get_tag_value("00", Some("04"))?;
+    let event_type = ppqr.try_get_tag_value("00", Some("02"))?;
+    let transaction_id = ppqr.try_get_tag_value("00", Some("03"))?;
+    let date = ppqr.try_get_tag_value("00", Some("04"))?;
 
-    Some(TrueMoneySlipVerifyData {
+    Ok(TrueMoneySlipVerifyData {
         event_type: event_type.to_string(),
         transaction_id: transaction_id.to_string(),
         date: date.to_string(),
@@ -64,4 +76,14 @@ mod tests {
         let payload = "invalid_payload";
         assert!(true_money_slip_verify(payload).is_none());
     }
+
+    #[test]
+    fn test_try_true_money_slip_verify_wrong_tag() {
+        // Regular Slip Verify QR has API type 000001 in sub-tag 00
+        let payload = "004100060000010103014022000111222233344ABCD125102TH910417DF";
+        assert!(matches!(
+            try_true_money_slip_verify(payload),
+            Err(PromptParseError::InvalidTagValue { tag_id, .. }) if tag_id == "00.00"
+        ));
+    }
 }
@@ -0,0 +1,191 @@
+use crate::additional_data::AdditionalData;
+use crate::amount::Amount;
+use crate::emvco_qr::EmvCoQr;
+use crate::generate::vietqr::{VietQrConfig, VietQrService, NAPAS_AID};
+use crate::promptpay::template;
+use crate::tlv::{decode, get_tag};
+
+/// Vietnamese bank in the NAPAS directory, identified by its 6-digit BIN
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VietQrBank {
+    /// Bank identification number
+    pub bin: &'static str,
+    /// Short name used by banking apps (e.g. `VCB`)
+    pub short_name: &'static str,
+    /// Full English name
+    pub name: &'static str,
+}
+
+const fn bank(bin: &'static str, short_name: &'static str, name: &'static str) -> VietQrBank {
+    VietQrBank {
+        bin,
+        short_name,
+        name,
+    }
+}
+
+#[rustfmt::skip]
+const BANKS: &[VietQrBank] = &[
+    bank("970400", "SGICB", "Saigon Industry and Commerce Bank"),
+    bank("970403", "STB", "Sacombank"),
+    bank("970405", "VBA", "Agribank"),
+    bank("970406", "DOB", "DongA Bank"),
+    bank("970407", "TCB", "Techcombank"),
+    bank("970409", "BAB", "Bac A Bank"),
+    bank("970412", "PVCB", "PVcomBank"),
+    bank("970414", "OCEANBANK", "Ocean Bank"),
+    bank("970415", "ICB", "VietinBank"),
+    bank("970416", "ACB", "Asia Commercial Bank"),
+    bank("970418", "BIDV", "Bank for Investment and Development of Vietnam"),
+    bank("970419", "NCB", "National Citizen Bank"),
+    bank("970422", "MB", "Military Commercial Bank"),
+    bank("970423", "TPB", "TPBank"),
+    bank("970424", "SHBVN", "Shinhan Bank Vietnam"),
+    bank("970425", "ABB", "An Binh Bank"),
+    bank("970426", "MSB", "Maritime Bank"),
+    bank("970427", "VAB", "VietABank"),
+    bank("970428", "NAB", "Nam A Bank"),
+    bank("970429", "SCB", "Sai Gon Commercial Bank"),
+    bank("970430", "PGB", "PG Bank"),
+    bank("970431", "EIB", "Eximbank"),
+    bank("970432", "VPB", "VPBank"),
+    bank("970433", "VIETBANK", "Vietnam Thuong Tin Bank"),
+    bank("970436", "VCB", "Vietcombank"),
+    bank("970437", "HDB", "HDBank"),
+    bank("970438", "BVB", "BaoViet Bank"),
+    bank("970440", "SEAB", "SeABank"),
+    bank("970441", "VIB", "Vietnam International Bank"),
+    bank("970443", "SHB", "Saigon-Hanoi Bank"),
+    bank("970448", "OCB", "Orient Commercial Bank"),
+    bank("970449", "LPB", "LPBank"),
+    bank("970452", "KLB", "Kienlongbank"),
+    bank("970454", "VCCB", "Viet Capital Bank"),
+    bank("970457", "WVN", "Woori Bank Vietnam"),
+    bank("970458", "UOB", "United Overseas Bank Vietnam"),
+];
+
+impl VietQrBank {
+    /// Look up a bank by its BIN
+    pub fn from_bin(bin: &str) -> Option<&'static VietQrBank> {
+        BANKS.iter().find(|bank| bank.bin == bin)
+    }
+
+    /// Look up a bank by its short name, case-insensitive
+    pub fn from_short_name(short_name: &str) -> Option<&'static VietQrBank> {
+        BANKS
+            .iter()
+            .find(|bank| bank.short_name.eq_ignore_ascii_case(short_name))
+    }
+
+    /// Every bank in the directory, ordered by BIN
+    pub fn all() -> &'static [VietQrBank] {
+        BANKS
+    }
+}
+
+/// Vietnam VietQR (NAPAS) QR data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VietQr {
+    /// 6-digit bank identification number (Tag 38.01.00)
+    pub bin: String,
+    /// Account or card number of the beneficiary (Tag 38.01.01)
+    pub account_number: String,
+    /// Transfer to an account or a card (Tag 38.02)
+    pub service: VietQrService,
+    /// Transaction amount
+    pub amount: Option<Amount>,
+    /// Currency code (ISO 4217 numeric)
+    pub currency: String,
+    /// Point of initiation is dynamic (`12`) instead of static (`11`)
+    pub is_dynamic: bool,
+    /// Additional Data Field Template (Tag 62)
+    pub additional_data: Option<AdditionalData>,
+}
+
+impl VietQr {
+    /// Read the VietQR fields of a parsed EMVCo QR
+    ///
+    /// Returns None if Tag 38 is not a NAPAS template or contains malformed values
+    pub fn from_emvco(qr: &EmvCoQr) -> Option<Self> {
+        let tag38 = template(qr, "38")?;
+        if get_tag(&tag38, "00", None)?.value != NAPAS_AID {
+            return None;
+        }
+
+        let beneficiary = decode(&get_tag(&tag38, "01", None)?.value).ok()?;
+        let service = VietQrService::from_code(&get_tag(&tag38, "02", None)?.value)?;
+        let amount = match qr.get_tag_value("54", None) {
+            Some(value) => Some(value.parse::<Amount>().ok()?),
+            None => None,
+        };
+
+        Some(VietQr {
+            bin: get_tag(&beneficiary, "00", None)?.value.clone(),
+            account_number: get_tag(&beneficiary, "01", None)?.value.clone(),
+            service,
+            amount,
+            currency: qr.get_tag_value("53", None)?.to_string(),
+            is_dynamic: qr.get_tag_value("01", None) == Some("12"),
+            additional_data: qr.additional_data(),
+        })
+    }
+
+    /// Beneficiary bank looked up in the built-in BIN directory
+    pub fn bank(&self) -> Option<&'static VietQrBank> {
+        VietQrBank::from_bin(&self.bin)
+    }
+
+    /// Transfer description (Purpose of Transaction, Tag 62.08)
+    pub fn purpose(&self) -> Option<&str> {
+        self.additional_data
+            .as_ref()?
+            .purpose_of_transaction
+            .as_deref()
+    }
+
+    /// Convert back into a config accepted by [`crate::generate::vietqr()`]
+    pub fn to_config(&self) -> VietQrConfig {
+        VietQrConfig {
+            bin: self.bin.clone(),
+            account_number: self.account_number.clone(),
+            service: self.service,
+            amount: self.amount,
+            additional_data: self.additional_data.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::vietqr;
+    use crate::parser::parse;
+    use crate::tlv::with_crc_tag;
+
+    #[test]
+    fn test_vietqr_round_trip() {
+        let payload = with_crc_tag(
+            "00020101021238570010A00000072701270006970436011300110019324180208QRIBFTTA\
+             530370454061000005802VN62150811CHUYEN TIEN",
+            "63",
+            true,
+        );
+        let qr = VietQr::from_emvco(&parse(&payload, true, true).unwrap()).unwrap();
+        assert_eq!(qr.bin, "970436");
+        assert_eq!(qr.account_number, "0011001932418");
+        assert_eq!(qr.service, VietQrService::Account);
+        assert_eq!(qr.amount, Some("100000".parse().unwrap()));
+        assert_eq!(qr.purpose(), Some("CHUYEN TIEN"));
+        assert_eq!(qr.bank().map(|bank| bank.short_name), Some("VCB"));
+        assert_eq!(vietqr(qr.to_config()).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_vietqr_bank_directory() {
+        assert_eq!(VietQrBank::from_bin("970418").unwrap().short_name, "BIDV");
+        assert_eq!(VietQrBank::from_short_name("tcb").unwrap().bin, "970407");
+        assert!(VietQrBank::from_bin("000000").is_none());
+        assert!(VietQrBank::all().windows(2).all(|w| w[0].bin < w[1].bin));
+    }
+}
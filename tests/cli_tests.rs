@@ -0,0 +1,129 @@
+// Tests for the promptparse command-line tool
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_promptparse"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run promptparse");
+
+    let mut child_stdin = child.stdin.take().unwrap();
+    child_stdin
+        .write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(child_stdin);
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_cli_gen_anyid() {
+    let output = run(
+        &[
+            "gen",
+            "anyid",
+            "--type",
+            "msisdn",
+            "--target",
+            "0812223333",
+            "--amount",
+            "30",
+            "--json",
+        ],
+        None,
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output).trim(),
+        r#"{"payload":"00020101021229370016A0000006770101110113006681222333353037645802TH540530.0063043CAD"}"#
+    );
+}
+
+#[test]
+fn test_cli_parse_from_stdin() {
+    let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+    let output = run(&["parse"], Some(payload));
+
+    assert!(output.status.success());
+    let table = stdout(&output);
+    assert!(table.contains("29:"));
+    assert!(table.contains("    01  0066812223333"));
+    assert!(table.contains("  63  1DCF"));
+}
+
+#[test]
+fn test_cli_validate_slip() {
+    let payload = "004100060000010103014022000111222233344ABCD126304BA3C";
+    let output = run(&["validate", "slip", payload, "--json"], None);
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output).trim(),
+        r#"{"valid":true,"sending_bank":"014","bank":"Siam Commercial Bank","trans_ref":"00111222233344ABCD12"}"#
+    );
+}
+
+#[test]
+fn test_cli_gen_slip_by_short_code() {
+    let output = run(
+        &[
+            "gen",
+            "slip",
+            "--bank",
+            "bbl",
+            "--trans-ref",
+            "0002123123121200011",
+        ],
+        None,
+    );
+
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("004000060000010103002021900021231231212000115102TH91049C30"));
+}
+
+#[test]
+fn test_cli_crc() {
+    let output = run(&["crc", "-", "--json"], Some("000201\n"));
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output).trim(),
+        r#"{"crc":"AAE6","payload":"0002016304AAE6"}"#
+    );
+}
+
+#[test]
+fn test_cli_errors() {
+    let output = run(&["parse", "0002"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("error:"));
+
+    let output = run(&["gen", "anyid", "--target", "0812223333"], None);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--type"));
+
+    let output = run(
+        &[
+            "gen",
+            "slip",
+            "--bank",
+            "kbnk",
+            "--trans-ref",
+            "0002123123121200011",
+        ],
+        None,
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown bank `kbnk`"));
+}
@@ -6,7 +6,7 @@ use promptparse::{
         AnyIdConfig, BillPaymentConfig, BotBarcodeConfig, ProxyType, SlipVerifyConfig,
         TrueMoneyConfig, TrueMoneySlipVerifyConfig,
     },
-    parse, parse_barcode, validate,
+    parse, parse_barcode, parse_duitnow, parse_promptpay, validate, Amount, PromptPayQr,
 };
 
 #[test]
@@ -50,6 +50,8 @@ fn test_generate_any_id() {
         proxy_type: ProxyType::Msisdn,
         target: "0812223333".to_string(),
         amount: None,
+        tip: None,
+        additional_data: None,
     };
     let result = any_id(config).unwrap();
     assert_eq!(
@@ -63,7 +65,9 @@ fn test_generate_any_id_with_amount() {
     let config = AnyIdConfig {
         proxy_type: ProxyType::Msisdn,
         target: "0812223333".to_string(),
-        amount: Some(30.0),
+        amount: Some(Amount::try_from(30.0).unwrap()),
+        tip: None,
+        additional_data: None,
     };
     let result = any_id(config).unwrap();
     assert_eq!(
@@ -91,8 +95,9 @@ fn test_generate_true_money_qr() {
         mobile_no: "0801111111".to_string(),
         amount: None,
         message: None,
+        additional_data: None,
     };
-    let result = true_money(config);
+    let result = true_money(config).unwrap();
     assert_eq!(
         result,
         "00020101021129390016A000000677010111031514000080111111153037645802TH63047C0F"
@@ -103,10 +108,11 @@ fn test_generate_true_money_qr() {
 fn test_generate_true_money_qr_with_amount_and_message() {
     let config = TrueMoneyConfig {
         mobile_no: "0801111111".to_string(),
-        amount: Some(10.05),
+        amount: Some(Amount::try_from(10.05).unwrap()),
         message: Some("Hello World!".to_string()),
+        additional_data: None,
     };
-    let result = true_money(config);
+    let result = true_money(config).unwrap();
     assert_eq!(result, "00020101021229390016A000000677010111031514000080111111153037645802TH540510.05814800480065006C006C006F00200057006F0072006C006400216304F5A2");
 }
 
@@ -115,9 +121,11 @@ fn test_generate_bill_payment_with_ref3() {
     let config = BillPaymentConfig {
         biller_id: "0112233445566".to_string(),
         amount: None,
+        tip: None,
         ref1: "CUSTOMER001".to_string(),
         ref2: Some("INV001".to_string()),
         ref3: Some("SCB".to_string()),
+        additional_data: None,
     };
     let result = bill_payment(config).unwrap();
     assert_eq!(result, "00020101021130620016A000000677010112011301122334455660211CUSTOMER0010306INV00153037645802TH62070703SCB6304780E");
@@ -141,7 +149,7 @@ fn test_generate_bot_barcode_with_ref2_and_amount() {
         biller_id: "099400016550100".to_string(),
         ref1: "123456789012".to_string(),
         ref2: Some("670429".to_string()),
-        amount: Some(3649.22),
+        amount: Some(Amount::try_from(3649.22).unwrap()),
     };
     let result = bot_barcode(config);
     assert_eq!(result, "|099400016550100\r123456789012\r670429\r364922");
@@ -215,11 +223,13 @@ fn test_generate_true_money_slip_verify() {
 fn test_any_id_with_national_id() {
     let config = AnyIdConfig {
         proxy_type: ProxyType::NatId,
-        target: "1234567890123".to_string(),
-        amount: Some(50.0),
+        target: "1234567890121".to_string(),
+        amount: Some(Amount::try_from(50.0).unwrap()),
+        tip: None,
+        additional_data: None,
     };
     let result = any_id(config).unwrap();
-    assert!(result.contains("1234567890123"));
+    assert!(result.contains("1234567890121"));
     assert!(result.contains("540550.00"));
 }
 
@@ -227,10 +237,12 @@ fn test_any_id_with_national_id() {
 fn test_bill_payment_with_amount() {
     let config = BillPaymentConfig {
         biller_id: "1234567890123".to_string(),
-        amount: Some(100.50),
+        amount: Some(Amount::try_from(100.50).unwrap()),
+        tip: None,
         ref1: "REF001".to_string(),
         ref2: None,
         ref3: None,
+        additional_data: None,
     };
     let result = bill_payment(config).unwrap();
     assert!(result.contains("5406100.50"));
@@ -273,8 +285,9 @@ fn test_true_money_with_message_encoding() {
         mobile_no: "0801111111".to_string(),
         amount: None,
         message: Some("Test".to_string()),
+        additional_data: None,
     };
-    let result = true_money(config);
+    let result = true_money(config).unwrap();
     assert!(result.contains("81160054006500730074"));
 }
 
@@ -285,7 +298,7 @@ fn test_bot_barcode_round_trip() {
         biller_id: "123456789012345".to_string(),
         ref1: "REF123".to_string(),
         ref2: Some("REF456".to_string()),
-        amount: Some(99.99),
+        amount: Some(Amount::try_from(99.99).unwrap()),
     };
 
     let barcode_str = bot_barcode(config);
@@ -294,7 +307,10 @@ fn test_bot_barcode_round_trip() {
     assert_eq!(parsed_barcode.biller_id, "123456789012345");
     assert_eq!(parsed_barcode.ref1, "REF123");
     assert_eq!(parsed_barcode.ref2, Some("REF456".to_string()));
-    assert_eq!(parsed_barcode.amount, Some(99.99));
+    assert_eq!(
+        parsed_barcode.amount,
+        Some(Amount::try_from(99.99).unwrap())
+    );
 }
 
 #[test]
@@ -309,3 +325,38 @@ fn test_checksum_validation() {
         "00020101021129370016A0000006770101110113006681222333353037645802TH6304FFFF";
     assert!(parse(invalid_payload, true, true).is_none());
 }
+
+#[test]
+fn test_parse_promptpay_and_regenerate() {
+    let payload =
+        "00020101021229370016A0000006770101110113006681222333353037645802TH540530.0063043CAD";
+    let qr = parse_promptpay(payload, true).unwrap();
+    assert_eq!(qr.target(), Some("0812223333"));
+    assert_eq!(qr.amount(), Some(Amount::try_from(30.0).unwrap()));
+
+    let PromptPayQr::AnyId(data) = qr else {
+        panic!("Expected AnyID QR");
+    };
+    assert_eq!(any_id(data.to_config()).unwrap(), payload);
+}
+
+#[test]
+#[ignore = "no PayNet-published DuitNow samples are vendored in tests/fixtures yet"]
+fn test_duitnow_published_samples() {
+    let fixture = std::fs::read_to_string("tests/fixtures/duitnow_published.txt").unwrap();
+    let samples: Vec<_> = fixture
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| line.split_once('\t').expect("<source>\t<payload>"))
+        .collect();
+    assert!(!samples.is_empty(), "no published samples in the fixture");
+
+    for (source, payload) in samples {
+        let qr = parse_duitnow(payload, true).unwrap_or_else(|| panic!("{source}: not parsed"));
+        assert_eq!(
+            promptparse::generate::duitnow(qr.to_config()).unwrap(),
+            payload,
+            "{source}: did not round-trip"
+        );
+    }
+}
@@ -84,7 +84,7 @@ fn test_bot_barcode_methods() {
         "123456789012345".to_string(),
         "REF001".to_string(),
         Some("REF002".to_string()),
-        Some(100.0),
+        Some(Amount::try_from(100.0).unwrap()),
     );
 
     // Test serialization
@@ -115,15 +115,19 @@ fn test_generate_module_accessibility() {
         proxy_type: ProxyType::Msisdn,
         target: "0812345678".to_string(),
         amount: None,
+        tip: None,
+        additional_data: None,
     };
     let _result = any_id(any_id_config);
 
     let bill_config = BillPaymentConfig {
         biller_id: "123456789012345".to_string(),
         amount: None,
+        tip: None,
         ref1: "REF001".to_string(),
         ref2: None,
         ref3: None,
+        additional_data: None,
     };
     let _result = bill_payment(bill_config);
 
@@ -131,6 +135,7 @@ fn test_generate_module_accessibility() {
         mobile_no: "0812345678".to_string(),
         amount: None,
         message: None,
+        additional_data: None,
     };
     let _result = true_money(true_money_config);
 
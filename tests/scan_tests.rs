@@ -0,0 +1,53 @@
+// Tests for decoding payloads from QR Code images
+#![cfg(feature = "scan")]
+
+use promptparse::scan::{scan_file, ScannedCode, ScannedData};
+use promptparse::PromptPayQr;
+
+fn sorted_by_x(mut codes: Vec<ScannedCode>) -> Vec<ScannedCode> {
+    codes.sort_by_key(|code| code.bounds.x);
+    codes
+}
+
+fn assert_fixture(codes: Vec<ScannedCode>) {
+    let codes = sorted_by_x(codes);
+    assert_eq!(codes.len(), 2);
+
+    assert_eq!(
+        codes[0].payload,
+        "004100060000010103014022000111222233344ABCD126304BA3C"
+    );
+    match &codes[0].data {
+        ScannedData::SlipVerify(data) => {
+            assert_eq!(data.sending_bank, "014");
+            assert_eq!(data.trans_ref, "00111222233344ABCD12");
+        }
+        other => panic!("expected slip verify, got {other:?}"),
+    }
+
+    match &codes[1].data {
+        ScannedData::PromptPay(qr) => match qr.as_ref() {
+            PromptPayQr::AnyId(qr) => assert_eq!(qr.target, "0812223333"),
+            other => panic!("expected PromptPay AnyID, got {other:?}"),
+        },
+        other => panic!("expected PromptPay AnyID, got {other:?}"),
+    }
+
+    // Both codes are 29 modules at 5px, drawn 40px from the top
+    for code in &codes {
+        assert!((38..=42).contains(&code.bounds.y));
+        assert!((140..=150).contains(&code.bounds.width));
+    }
+    assert!((38..=42).contains(&codes[0].bounds.x));
+    assert!((223..=227).contains(&codes[1].bounds.x));
+}
+
+#[test]
+fn test_scan_png_file() {
+    assert_fixture(scan_file("tests/fixtures/slip_and_promptpay.png").unwrap());
+}
+
+#[test]
+fn test_scan_jpeg_file() {
+    assert_fixture(scan_file("tests/fixtures/slip_and_promptpay.jpg").unwrap());
+}
@@ -0,0 +1,189 @@
+// Tests for Serialize / Deserialize of public data types
+#![cfg(feature = "serde")]
+
+use promptparse::generate::{any_id, AnyIdConfig, ProxyType, TipPolicy};
+use promptparse::schema::TagSchema;
+use promptparse::validate::{slip_verify, BcelOneProofData, SlipVerifyData};
+use promptparse::{parse, parse_promptpay, try_parse_with, Amount, EmvCoQr, PromptPayQr, TlvTag};
+use serde_json::json;
+
+#[test]
+fn test_serde_proxy_type() {
+    assert_eq!(
+        serde_json::to_value(ProxyType::Msisdn).unwrap(),
+        json!("msisdn")
+    );
+    assert_eq!(
+        serde_json::to_value(ProxyType::EWalletId).unwrap(),
+        json!("ewallet")
+    );
+
+    let from = |value| serde_json::from_value::<ProxyType>(value).ok();
+    assert_eq!(from(json!("natid")), Some(ProxyType::NatId));
+    assert_eq!(from(json!("MSISDN")), Some(ProxyType::Msisdn));
+    assert_eq!(from(json!("03")), Some(ProxyType::EWalletId));
+    assert_eq!(from(json!(4)), Some(ProxyType::BankAcc));
+    assert_eq!(from(json!("99")), None);
+    assert_eq!(from(json!(-1)), None);
+}
+
+#[test]
+fn test_serde_amount() {
+    let amount = Amount::from_satang(3050).unwrap();
+    assert_eq!(serde_json::to_value(amount).unwrap(), json!("30.50"));
+
+    let from = |value| serde_json::from_value::<Amount>(value).ok();
+    assert_eq!(from(json!("30.50")).unwrap().to_string(), "30.50");
+    assert_eq!(from(json!(30)).unwrap().to_string(), "30.00");
+    assert_eq!(from(json!(0.29)).unwrap().to_string(), "0.29");
+    assert_eq!(from(json!(123456.789)), None);
+    assert_eq!(from(json!("abc")), None);
+    assert_eq!(from(json!(-5)), None);
+}
+
+#[test]
+fn test_serde_emvco_qr_tag_tree() {
+    let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+    let qr = parse(payload, true, true).unwrap();
+
+    let value = serde_json::to_value(&qr).unwrap();
+    assert_eq!(value["payload"], json!(payload));
+    assert_eq!(
+        value["tags"][0],
+        json!({"id": "00", "value": "01", "sub_tags": null, "length": 2})
+    );
+    assert_eq!(value["tags"][2]["id"], json!("29"));
+    assert_eq!(
+        value["tags"][2]["sub_tags"][1],
+        json!({"id": "01", "value": "0066812223333", "sub_tags": null, "length": 13})
+    );
+
+    let back: EmvCoQr = serde_json::from_value(value).unwrap();
+    assert_eq!(back, qr);
+}
+
+#[test]
+fn test_serde_emvco_qr_rejects_inconsistent_data() {
+    let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF";
+    let from = |value| serde_json::from_value::<EmvCoQr>(value);
+
+    // Tags may be omitted and are rebuilt from the payload
+    let qr = from(json!({"payload": payload})).unwrap();
+    assert_eq!(qr, parse(payload, true, true).unwrap());
+
+    // Tags that disagree with the payload
+    let mut value = serde_json::to_value(&qr).unwrap();
+    value["tags"][1]["value"] = json!("12");
+    assert!(from(value).is_err());
+
+    // Tags that no longer encode to the payload
+    let mut value = serde_json::to_value(&qr).unwrap();
+    value["payload"] = json!(payload.replace("0066812223333", "0066899999999"));
+    assert!(from(value).is_err());
+
+    let tag = |value| serde_json::from_value::<TlvTag>(value);
+    assert_eq!(
+        tag(json!({"id": "00", "value": "01"})).unwrap(),
+        TlvTag::new("00".to_string(), "01".to_string())
+    );
+    assert!(tag(json!({"id": "00", "value": "01", "length": 5})).is_err());
+    assert!(tag(json!({"id": "0", "value": "01", "length": 2})).is_err());
+    assert!(tag(json!({
+        "id": "29",
+        "value": "0002AB",
+        "sub_tags": [{"id": "00", "value": "CD", "length": 2}],
+        "length": 6
+    }))
+    .is_err());
+}
+
+#[test]
+fn test_serde_emvco_qr_non_strict_and_custom_schema() {
+    let round_trip = |qr: &EmvCoQr| {
+        let json = serde_json::to_string(qr).unwrap();
+        assert_eq!(&serde_json::from_str::<EmvCoQr>(&json).unwrap(), qr);
+    };
+
+    // Bad checksum, parsed without CRC validation
+    let payload = "00020101021129370016A0000006770101110113006681222333353037645802TH6304FFFF";
+    round_trip(&parse(payload, false, true).unwrap());
+    round_trip(&parse(payload, false, false).unwrap());
+
+    // Slip Verify parsed with its own schema
+    let slip = "004100060000010103014022000111222233344ABCD125102TH910417DF";
+    round_trip(&try_parse_with(slip, false, TagSchema::slip_verify()).unwrap());
+
+    // Unknown QR with a bad checksum kept as is
+    match parse_promptpay(&slip.replace("17DF", "FFFF"), false) {
+        Some(qr @ PromptPayQr::Unknown(_)) => {
+            let json = serde_json::to_string(&qr).unwrap();
+            assert_eq!(serde_json::from_str::<PromptPayQr>(&json).unwrap(), qr);
+        }
+        other => panic!("expected unknown QR, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_serde_generator_config() {
+    let config: AnyIdConfig = serde_json::from_value(json!({
+        "proxy_type": "msisdn",
+        "target": "0812223333",
+        "amount": "30.00",
+        "tip": {"type": "fixed", "value": "5.00"},
+    }))
+    .unwrap();
+
+    assert_eq!(
+        config.tip,
+        Some(TipPolicy::Fixed(Amount::from_satang(500).unwrap()))
+    );
+    assert!(config.additional_data.is_none());
+
+    let value = serde_json::to_value(&config).unwrap();
+    assert_eq!(value["proxy_type"], json!("msisdn"));
+    assert_eq!(value["amount"], json!("30.00"));
+    assert_eq!(value["tip"], json!({"type": "fixed", "value": "5.00"}));
+    assert_eq!(
+        serde_json::to_value(TipPolicy::Prompt).unwrap(),
+        json!({"type": "prompt"})
+    );
+
+    let round_trip: AnyIdConfig = serde_json::from_value(value).unwrap();
+    assert_eq!(any_id(round_trip).unwrap(), any_id(config).unwrap());
+}
+
+#[test]
+fn test_serde_typed_results() {
+    let qr = parse_promptpay(
+        "00020101021129370016A0000006770101110113006681222333353037645802TH63041DCF",
+        true,
+    )
+    .unwrap();
+    let value = serde_json::to_value(&qr).unwrap();
+    assert_eq!(value["type"], json!("any_id"));
+    assert_eq!(value["target"], json!("0812223333"));
+    assert_eq!(serde_json::from_value::<PromptPayQr>(value).unwrap(), qr);
+
+    let slip = slip_verify(
+        "004100060000010103014022000111222233344ABCD126304BA3C",
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&slip).unwrap(),
+        json!({"sending_bank": "014", "trans_ref": "00111222233344ABCD12"})
+    );
+    let back: SlipVerifyData =
+        serde_json::from_value(serde_json::to_value(&slip).unwrap()).unwrap();
+    assert_eq!(back, slip);
+
+    let bcel = BcelOneProofData {
+        r#type: Some("01".to_string()),
+        ticket: None,
+        fccref: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&bcel).unwrap(),
+        json!({"type": "01", "ticket": null, "fccref": null})
+    );
+}